@@ -1,35 +1,79 @@
 #![allow(non_snake_case)]
 
+// `main` drives one lab at a time, so unused searchers are only reported in the test build
+#[cfg_attr(not(test), allow(dead_code))]
 mod searchers {
     mod descent_searchers {
+        mod broyden;
+        mod conjugate_gradient;
         mod gauss;
+        mod heavy_ball;
+        mod hooke_jeeves;
+        mod lbfgs;
+        mod nelder_mead;
+        mod nesterov;
+        mod newton;
+        mod powell;
+        mod rosenbrock;
         pub mod search;
+        mod steepest_descent;
+        mod trust_region;
+        mod variable_metric;
     }
     mod extremum_searcher;
+    mod least_squares {
+        mod gauss_newton;
+        mod levenberg_marquardt;
+        pub mod search;
+    }
+    mod line_searchers {
+        mod armijo;
+        mod goldstein;
+        mod more_thuente;
+        pub mod search;
+    }
+    mod penalty_methods;
     mod one_dimension_searchers {
+        mod brent;
+        mod cubic;
+        mod dichotomy;
         mod fibonacci;
+        mod golden_ratio;
+        mod newton;
+        mod piyavskii;
+        pub mod search;
+        mod secant;
+    }
+    mod root_searchers {
+        mod bisection;
+        mod brent;
+        mod newton;
+        mod regula_falsi;
         pub mod search;
+        mod secant;
     }
     mod random_searchers {
         mod global {
-            mod first;
-            mod second;
-            mod third;
-        };
-        mod simple;
+            pub mod first;
+            pub mod second;
+        }
         mod search;
+        mod simple;
+    }
+    mod stochastic_searchers {
+        mod adagrad;
+        mod adam;
+        mod rmsprop;
+        pub mod search;
+        mod sgd;
     }
 }
 
-use std::fs;
-use std::sync::Arc;
-
-use nalgebra::Vector2;
-
 //#region definitions
 //#endregion
 
-const STATS_HEADER: &'static str = "\"function calls\";\"iterations\";\"x\";\"y\";\"f(x, y)\";\n";
+#[allow(dead_code)]
+const STATS_HEADER: &str = "\"function calls\";\"iterations\";\"x\";\"y\";\"f(x, y)\";\n";
 
 fn main() -> std::io::Result<()> {
     Ok(())
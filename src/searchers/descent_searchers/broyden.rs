@@ -0,0 +1,277 @@
+use super::search::{DescentSearcher, Gradient};
+use crate::searchers::extremum_searcher;
+use crate::searchers::extremum_searcher::{IterationResult, SearchError};
+use crate::searchers::line_searchers;
+use nalgebra::{allocator::Allocator, DefaultAllocator, Dim, MatrixN, RealField, VectorN};
+use std::sync::Arc;
+
+pub struct Broyden<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: Dim,
+    DefaultAllocator: Allocator<Scalar, Dimension> + Allocator<Scalar, Dimension, Dimension>,
+{
+    x: VectorN<Scalar, Dimension>,
+    dx: VectorN<Scalar, Dimension>,
+    g: VectorN<Scalar, Dimension>,
+    eta: MatrixN<Scalar, Dimension>,
+    func_calls: usize,
+    derivative_calls: usize,
+    iters: usize,
+    error: Option<SearchError>,
+    f: Arc<dyn Fn(VectorN<Scalar, Dimension>) -> Scalar>,
+    df: Gradient<Scalar, Dimension>,
+    eps: Scalar,
+    max_iters: usize,
+    comparator: std::cmp::Ordering,
+    line_search: line_searchers::search::Method<Scalar>,
+    restart: bool,
+}
+
+impl<Scalar, Dimension> Broyden<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: Dim,
+    DefaultAllocator: Allocator<Scalar, Dimension> + Allocator<Scalar, Dimension, Dimension>,
+{
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        x0: VectorN<Scalar, Dimension>,
+        f: Arc<dyn Fn(VectorN<Scalar, Dimension>) -> Scalar>,
+        df: Gradient<Scalar, Dimension>,
+        comparator: std::cmp::Ordering,
+        line_search: line_searchers::search::Method<Scalar>,
+        restart: bool,
+        eps: Scalar,
+        max_iters: usize,
+    ) -> Self {
+        let g = df(x0.clone());
+        Self {
+            dx: x0.map(|_| Scalar::max_value()),
+            eta: Self::initial_metric(comparator, x0.len()),
+            x: x0,
+            g,
+            f,
+            df,
+            eps,
+            iters: 0,
+            error: None,
+            func_calls: 0,
+            derivative_calls: 1,
+            max_iters,
+            comparator,
+            line_search,
+            restart,
+        }
+    }
+
+    // approximates the inverse hessian, which is negative definite near a maximum
+    fn initial_metric(comparator: std::cmp::Ordering, n: usize) -> MatrixN<Scalar, Dimension> {
+        let n = Dimension::from_usize(n);
+        if comparator == std::cmp::Ordering::Less {
+            MatrixN::<Scalar, Dimension>::identity_generic(n, n)
+        } else {
+            -MatrixN::<Scalar, Dimension>::identity_generic(n, n)
+        }
+    }
+
+    // the approximation of the inverse hessian
+    pub fn metric(&self) -> MatrixN<Scalar, Dimension> {
+        self.eta.clone()
+    }
+}
+
+impl<Scalar, Dimension> DescentSearcher<Scalar, Dimension> for Broyden<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: Dim,
+    DefaultAllocator: Allocator<Scalar, Dimension> + Allocator<Scalar, Dimension, Dimension>,
+{
+    fn S(&self) -> VectorN<Scalar, Dimension> {
+        -(&self.eta * &self.g)
+    }
+}
+
+impl<Scalar, Dimension> extremum_searcher::Search<VectorN<Scalar, Dimension>>
+    for Broyden<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: Dim,
+    DefaultAllocator: Allocator<Scalar, Dimension> + Allocator<Scalar, Dimension, Dimension>,
+{
+    fn iters(&self) -> usize {
+        self.iters
+    }
+    fn error(&self) -> Option<SearchError> {
+        self.error
+    }
+    fn func_calls(&self) -> usize {
+        self.func_calls
+    }
+    fn derivative_calls(&self) -> usize {
+        self.derivative_calls
+    }
+    fn x(&self) -> VectorN<Scalar, Dimension> {
+        self.x.clone()
+    }
+    fn dx(&self) -> VectorN<Scalar, Dimension> {
+        self.dx.clone()
+    }
+}
+
+impl<Scalar, Dimension> Iterator for Broyden<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: Dim,
+    DefaultAllocator: Allocator<Scalar, Dimension> + Allocator<Scalar, Dimension, Dimension>,
+{
+    type Item = IterationResult<VectorN<Scalar, Dimension>>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let is_extra = self.g.norm() < self.eps
+            || self.dx.iter().all(|xi| xi.abs() < self.eps)
+            || self.iters >= self.max_iters;
+        // nothing is searched once the search is over, the direction may no longer descend
+        if is_extra {
+            return Some(IterationResult::new(
+                self.x.clone(),
+                self.x.map(|_| Scalar::zero()),
+                0,
+                true,
+            ));
+        }
+        let x = self.x.clone();
+        let f = self.f.clone();
+        let df = self.df.clone();
+        let s = self.S();
+        let (_x, _s) = (x.clone(), s.clone());
+        let lambda_result = match line_searchers::search::Search::result(
+            Arc::new(move |lambda| f(x.clone() + s.clone() * lambda)),
+            Some(Arc::new(move |lambda| {
+                df(_x.clone() + _s.clone() * lambda).dot(&_s)
+            })),
+            self.comparator,
+            self.line_search.clone(),
+            Scalar::one(),
+            self.eps,
+            self.max_iters,
+        ) {
+            Ok(lambda_result) => lambda_result,
+            Err(error) => {
+                self.error = Some(error);
+                return None;
+            }
+        };
+        self.dx = self.S() * lambda_result.x();
+        self.x += self.dx.clone();
+        self.iters += 1;
+        let dg = (self.df)(self.x.clone()) - self.g.clone();
+        self.g += dg.clone();
+
+        if self.restart && self.iters.is_multiple_of(self.x.len()) {
+            self.eta = Self::initial_metric(self.comparator, self.x.len());
+        } else {
+            let v = self.dx.clone() - &self.eta * &dg;
+            let denominator = v.dot(&dg);
+            if denominator.abs() > Scalar::default_epsilon() * v.norm() * dg.norm() {
+                self.eta
+                    .ger(Scalar::one() / denominator, &v, &v, Scalar::one());
+            }
+        }
+
+        self.func_calls += lambda_result.func_calls();
+        self.derivative_calls += lambda_result.derivative_calls() + 1;
+        Some(
+            IterationResult::new(
+                self.x.clone(),
+                self.dx.clone(),
+                lambda_result.func_calls(),
+                is_extra,
+            )
+            .with_derivative_calls(lambda_result.derivative_calls() + 1),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::searchers::descent_searchers::search::{quadratic, Method, Search};
+    use crate::searchers::one_dimension_searchers;
+    use crate::searchers::one_dimension_searchers::search::Bracketing;
+    use nalgebra::{Matrix2, Vector2, U2};
+
+    type Function = Arc<dyn Fn(Vector2<f64>) -> f64>;
+
+    fn line_search() -> line_searchers::search::Method<f64> {
+        line_searchers::search::Method::Exact {
+            method: one_dimension_searchers::search::Method::Brent,
+            bracketing: Bracketing::default(),
+        }
+    }
+
+    // with the greater comparator the search maximises `-f`
+    fn broyden(comparator: std::cmp::Ordering, restart: bool) -> Broyden<f64, U2> {
+        let (f, df) = quadratic();
+        let (f, df): (Function, Gradient<f64, U2>) = if comparator == std::cmp::Ordering::Less {
+            (f, df)
+        } else {
+            (
+                Arc::new(|x| -4.0 * (x[0] - 1.0).powi(2) - (x[1] + 2.0).powi(2) - x[0] * x[1]),
+                Arc::new(|x| Vector2::new(-8.0 * (x[0] - 1.0) - x[1], -2.0 * (x[1] + 2.0) - x[0])),
+            )
+        };
+        Broyden::new(
+            Vector2::new(-3.0, 4.0),
+            f,
+            df,
+            comparator,
+            line_search(),
+            restart,
+            1e-9,
+            100,
+        )
+    }
+
+    #[test]
+    fn minimises_a_quadratic_with_and_without_restart() {
+        for restart in [false, true] {
+            let (f, df) = quadratic();
+            let result = Search::Mnimimum(
+                Vector2::new(-3.0, 4.0),
+                f,
+                Method::Broyden {
+                    df: df.clone(),
+                    line_search: line_search(),
+                    restart,
+                },
+                1e-9,
+                100,
+            )
+            .unwrap();
+            assert!(df(result.x()).norm() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn maximises_with_the_greater_comparator() {
+        use crate::searchers::extremum_searcher::Search as _;
+        let result = broyden(std::cmp::Ordering::Greater, false).result();
+        let (_, df) = quadratic();
+        assert!(df(result.x()).norm() < 1e-6);
+    }
+
+    #[test]
+    fn metric_approaches_the_inverse_hessian() {
+        let mut searcher = broyden(std::cmp::Ordering::Less, false);
+        searcher.nth(1).unwrap();
+        let inverse = Matrix2::new(8.0, 1.0, 1.0, 2.0).try_inverse().unwrap();
+        assert!((searcher.metric() - inverse).norm() < 1e-6);
+    }
+
+    #[test]
+    fn restart_resets_the_metric_every_dimension_iterations() {
+        let mut searcher = broyden(std::cmp::Ordering::Less, true);
+        searcher.nth(1).unwrap();
+        assert_eq!(searcher.metric(), Matrix2::identity());
+    }
+}
@@ -0,0 +1,275 @@
+use super::search::{Beta, DescentSearcher, Gradient};
+use crate::searchers::extremum_searcher;
+use crate::searchers::extremum_searcher::{IterationResult, SearchError};
+use crate::searchers::line_searchers;
+use nalgebra::{allocator::Allocator, DefaultAllocator, Dim, RealField, VectorN};
+use std::sync::Arc;
+
+pub struct ConjugateGradient<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: Dim,
+    DefaultAllocator: Allocator<Scalar, Dimension>,
+{
+    x: VectorN<Scalar, Dimension>,
+    dx: VectorN<Scalar, Dimension>,
+    g: VectorN<Scalar, Dimension>,
+    s: VectorN<Scalar, Dimension>,
+    sign: Scalar,
+    func_calls: usize,
+    derivative_calls: usize,
+    iters: usize,
+    error: Option<SearchError>,
+    f: Arc<dyn Fn(VectorN<Scalar, Dimension>) -> Scalar>,
+    df: Gradient<Scalar, Dimension>,
+    eps: Scalar,
+    max_iters: usize,
+    comparator: std::cmp::Ordering,
+    line_search: line_searchers::search::Method<Scalar>,
+    formula: Beta,
+    beta: Scalar,
+    restart: bool,
+}
+
+impl<Scalar, Dimension> ConjugateGradient<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: Dim,
+    DefaultAllocator: Allocator<Scalar, Dimension>,
+{
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        x0: VectorN<Scalar, Dimension>,
+        f: Arc<dyn Fn(VectorN<Scalar, Dimension>) -> Scalar>,
+        df: Gradient<Scalar, Dimension>,
+        comparator: std::cmp::Ordering,
+        line_search: line_searchers::search::Method<Scalar>,
+        beta: Beta,
+        restart: bool,
+        eps: Scalar,
+        max_iters: usize,
+    ) -> Self {
+        // the gradient of `sign * f` is kept, so the directions always descend
+        let sign = if comparator == std::cmp::Ordering::Less {
+            Scalar::one()
+        } else {
+            -Scalar::one()
+        };
+        let g = df(x0.clone()) * sign;
+        Self {
+            dx: x0.map(|_| Scalar::max_value()),
+            x: x0,
+            s: -g.clone(),
+            g,
+            sign,
+            f,
+            df,
+            eps,
+            iters: 0,
+            error: None,
+            func_calls: 0,
+            derivative_calls: 1,
+            max_iters,
+            comparator,
+            line_search,
+            formula: beta,
+            beta: Scalar::zero(),
+            restart,
+        }
+    }
+
+    // the beta of the last iteration, zero when it restarted along the antigradient
+    pub fn beta(&self) -> Scalar {
+        self.beta
+    }
+
+    fn next_beta(&self, g: &VectorN<Scalar, Dimension>) -> Scalar {
+        let y = g - &self.g;
+        let (numerator, denominator) = match self.formula {
+            Beta::FletcherReeves => (g.norm_squared(), self.g.norm_squared()),
+            Beta::PolakRibierePlus => (g.dot(&y).max(Scalar::zero()), self.g.norm_squared()),
+            Beta::HestenesStiefel => (g.dot(&y), self.s.dot(&y)),
+            Beta::DaiYuan => (g.norm_squared(), self.s.dot(&y)),
+        };
+        if denominator.abs() > Scalar::default_epsilon() {
+            numerator / denominator
+        } else {
+            Scalar::zero()
+        }
+    }
+}
+
+impl<Scalar, Dimension> DescentSearcher<Scalar, Dimension> for ConjugateGradient<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: Dim,
+    DefaultAllocator: Allocator<Scalar, Dimension>,
+{
+    fn S(&self) -> VectorN<Scalar, Dimension> {
+        self.s.clone()
+    }
+}
+
+impl<Scalar, Dimension> extremum_searcher::Search<VectorN<Scalar, Dimension>>
+    for ConjugateGradient<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: Dim,
+    DefaultAllocator: Allocator<Scalar, Dimension>,
+{
+    fn iters(&self) -> usize {
+        self.iters
+    }
+    fn error(&self) -> Option<SearchError> {
+        self.error
+    }
+    fn func_calls(&self) -> usize {
+        self.func_calls
+    }
+    fn derivative_calls(&self) -> usize {
+        self.derivative_calls
+    }
+    fn x(&self) -> VectorN<Scalar, Dimension> {
+        self.x.clone()
+    }
+    fn dx(&self) -> VectorN<Scalar, Dimension> {
+        self.dx.clone()
+    }
+}
+
+impl<Scalar, Dimension> Iterator for ConjugateGradient<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: Dim,
+    DefaultAllocator: Allocator<Scalar, Dimension>,
+{
+    type Item = IterationResult<VectorN<Scalar, Dimension>>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let is_extra = self.g.norm() < self.eps
+            || self.dx.iter().all(|xi| xi.abs() < self.eps)
+            || self.iters >= self.max_iters;
+        // nothing is searched once the search is over, the direction may no longer descend
+        if is_extra {
+            return Some(IterationResult::new(
+                self.x.clone(),
+                self.x.map(|_| Scalar::zero()),
+                0,
+                true,
+            ));
+        }
+        let x = self.x.clone();
+        let f = self.f.clone();
+        let df = self.df.clone();
+        let s = self.S();
+        let (_x, _s) = (x.clone(), s.clone());
+        let lambda_result = match line_searchers::search::Search::result(
+            Arc::new(move |lambda| f(x.clone() + s.clone() * lambda)),
+            Some(Arc::new(move |lambda| {
+                df(_x.clone() + _s.clone() * lambda).dot(&_s)
+            })),
+            self.comparator,
+            self.line_search.clone(),
+            Scalar::one(),
+            self.eps,
+            self.max_iters,
+        ) {
+            Ok(lambda_result) => lambda_result,
+            Err(error) => {
+                self.error = Some(error);
+                return None;
+            }
+        };
+        self.dx = self.S() * lambda_result.x();
+        self.x += self.dx.clone();
+        self.iters += 1;
+        let g = (self.df)(self.x.clone()) * self.sign;
+
+        // restart along the antigradient every n iterations or once the direction stops descending
+        let mut beta = self.next_beta(&g);
+        let mut s = self.s.clone() * beta - g.clone();
+        if (self.restart && self.iters.is_multiple_of(self.x.len())) || s.dot(&g) >= Scalar::zero()
+        {
+            beta = Scalar::zero();
+            s = -g.clone();
+        }
+        self.g = g;
+        self.s = s;
+        self.beta = beta;
+
+        self.func_calls += lambda_result.func_calls();
+        self.derivative_calls += lambda_result.derivative_calls() + 1;
+        Some(
+            IterationResult::new(
+                self.x.clone(),
+                self.dx.clone(),
+                lambda_result.func_calls(),
+                is_extra,
+            )
+            .with_derivative_calls(lambda_result.derivative_calls() + 1),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::searchers::descent_searchers::search::{quadratic, Method, Search};
+    use crate::searchers::one_dimension_searchers;
+    use crate::searchers::one_dimension_searchers::search::Bracketing;
+    use nalgebra::Vector2;
+
+    fn exact() -> line_searchers::search::Method<f64> {
+        line_searchers::search::Method::Exact {
+            method: one_dimension_searchers::search::Method::Brent,
+            bracketing: Bracketing::default(),
+        }
+    }
+
+    #[test]
+    fn every_beta_minimises_a_quadratic() {
+        for beta in [
+            Beta::FletcherReeves,
+            Beta::PolakRibierePlus,
+            Beta::HestenesStiefel,
+            Beta::DaiYuan,
+        ] {
+            let (f, df) = quadratic();
+            let result = Search::Mnimimum(
+                Vector2::new(-3.0, 4.0),
+                f,
+                Method::ConjugateGradient {
+                    df: df.clone(),
+                    line_search: exact(),
+                    beta,
+                    restart: true,
+                },
+                1e-9,
+                100,
+            )
+            .unwrap();
+            assert!(df(result.x()).norm() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn fletcher_reeves_beta_is_reported_in_full_precision() {
+        let (f, df) = quadratic();
+        let x0 = Vector2::new(-3.0, 4.0);
+        let mut searcher = ConjugateGradient::new(
+            x0,
+            f,
+            df.clone(),
+            std::cmp::Ordering::Less,
+            exact(),
+            Beta::FletcherReeves,
+            false,
+            1e-9,
+            100,
+        );
+        let r = searcher.next().unwrap();
+        let expected = df(r.x()).norm_squared() / df(x0).norm_squared();
+        assert!((searcher.beta() - expected).abs() < 1e-12);
+        let s = -df(r.x()) - df(x0) * expected;
+        assert!((searcher.S() - s).norm() < 1e-12);
+    }
+}
@@ -1,62 +1,186 @@
-use super::search::DescentSearcher;
+use super::search::{DescentSearcher, Selection};
 use crate::searchers::extremum_searcher;
-use crate::searchers::extremum_searcher::IterationResult;
-use crate::searchers::one_dimension_searchers;
-use nalgebra::{allocator::Allocator, DefaultAllocator, DimName, RealField, VectorN};
+use crate::searchers::extremum_searcher::{IterationResult, SearchError};
+use crate::searchers::line_searchers;
+use nalgebra::{allocator::Allocator, DefaultAllocator, Dim, RealField, VectorN};
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
 use std::sync::Arc;
 
 pub struct Gauss<Scalar, Dimension>
 where
     Scalar: RealField,
-    Dimension: DimName,
+    Dimension: Dim,
     DefaultAllocator: Allocator<Scalar, Dimension>,
 {
     x: VectorN<Scalar, Dimension>,
     dx: VectorN<Scalar, Dimension>,
+    blocks: Vec<Vec<usize>>,
+    // the block moved along by the last iteration and the direction of the move
+    block: usize,
+    s: VectorN<Scalar, Dimension>,
+    // order of the blocks in the current sweep, permutation selection only
+    order: Vec<usize>,
+    // largest coordinate change of the last move along every block
+    moves: Vec<Scalar>,
+    rng: rand::rngs::StdRng,
     func_calls: usize,
+    derivative_calls: usize,
     iters: usize,
+    error: Option<SearchError>,
     f: Arc<dyn Fn(VectorN<Scalar, Dimension>) -> Scalar>,
     eps: Scalar,
     max_iters: usize,
     comparator: std::cmp::Ordering,
+    line_search: line_searchers::search::Method<Scalar>,
+    selection: Selection<Scalar, Dimension>,
 }
 
 impl<Scalar, Dimension> Gauss<Scalar, Dimension>
 where
     Scalar: RealField,
-    Dimension: DimName,
+    Dimension: Dim,
     DefaultAllocator: Allocator<Scalar, Dimension>,
 {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         x0: VectorN<Scalar, Dimension>,
         f: Arc<dyn Fn(VectorN<Scalar, Dimension>) -> Scalar>,
         comparator: std::cmp::Ordering,
+        line_search: line_searchers::search::Method<Scalar>,
+        selection: Selection<Scalar, Dimension>,
+        blocks: Option<Vec<Vec<usize>>>,
         eps: Scalar,
         max_iters: usize,
     ) -> Self {
+        let n = x0.len();
+        let blocks = blocks.unwrap_or_else(|| (0..n).map(|i| vec![i]).collect());
+        // every block needs a coordinate to move along, and the coordinates must exist
+        let error = if blocks.is_empty()
+            || blocks
+                .iter()
+                .any(|block| block.is_empty() || block.iter().any(|&i| i >= n))
+        {
+            Some(SearchError::InvalidParameter("blocks"))
+        } else {
+            None
+        };
+        let seed = match selection {
+            Selection::Random { seed } | Selection::Permutation { seed } => seed,
+            _ => 0,
+        };
         Self {
-            x: x0.clone(),
-            dx: VectorN::<Scalar, Dimension>::from_element(Scalar::max_value()),
+            dx: x0.map(|_| Scalar::max_value()),
+            s: x0.map(|_| Scalar::zero()),
+            x: x0,
+            block: 0,
+            order: (0..blocks.len()).collect(),
+            moves: vec![Scalar::max_value(); blocks.len()],
+            blocks,
+            rng: rand::rngs::StdRng::seed_from_u64(seed),
             f,
             eps,
             iters: 0,
+            error,
             func_calls: 0,
+            derivative_calls: 0,
             max_iters,
             comparator,
+            line_search,
+            selection,
         }
     }
+
+    // the block with the largest norm of `g` over its coordinates
+    fn largest(&self, g: &VectorN<Scalar, Dimension>) -> usize {
+        let norms: Vec<Scalar> = self
+            .blocks
+            .iter()
+            .map(|block| block.iter().fold(Scalar::zero(), |a, &i| a + g[i] * g[i]))
+            .collect();
+        (0..norms.len()).fold(0, |a, b| if norms[b] > norms[a] { b } else { a })
+    }
+
+    // forward differences of `f` with step `h` along the given coordinates, zero elsewhere
+    fn differences(&self, coordinates: &[usize], h: Scalar) -> VectorN<Scalar, Dimension> {
+        let fx = (self.f)(self.x.clone());
+        let mut g = self.x.map(|_| Scalar::zero());
+        for &i in coordinates {
+            let mut x = self.x.clone();
+            x[i] += h;
+            g[i] = ((self.f)(x) - fx) / h;
+        }
+        g
+    }
+
+    // chooses the next block, returns the function and derivative calls spent on choosing and
+    // the gradient when the choice needed one
+    fn select(&mut self) -> (usize, usize, Option<VectorN<Scalar, Dimension>>) {
+        let n = self.blocks.len();
+        match &self.selection {
+            Selection::Cyclic => {
+                self.block = self.iters % n;
+                (0, 0, None)
+            }
+            Selection::GaussSouthwell { df } => {
+                let g = df(self.x.clone());
+                self.block = self.largest(&g);
+                (0, 1, Some(g))
+            }
+            Selection::FiniteDifference { h } => {
+                let coordinates: Vec<usize> = (0..self.x.len()).collect();
+                let g = self.differences(&coordinates, *h);
+                self.block = self.largest(&g);
+                (self.x.len() + 1, 0, Some(g))
+            }
+            Selection::Random { .. } => {
+                self.block = self.rng.gen_range(0, n);
+                (0, 0, None)
+            }
+            Selection::Permutation { .. } => {
+                let k = self.iters % n;
+                if k == 0 {
+                    self.order.shuffle(&mut self.rng);
+                }
+                self.block = self.order[k];
+                (0, 0, None)
+            }
+        }
+    }
+
+    // a single coordinate is searched along its axis, a larger block along the gradient
+    // restricted to it, returns the function calls spent on the gradient
+    fn direction(&mut self, g: Option<VectorN<Scalar, Dimension>>) -> usize {
+        let block = self.blocks[self.block].clone();
+        if let [i] = block[..] {
+            self.s = self.x.map(|_| Scalar::zero());
+            self.s[i] = Scalar::one();
+            return 0;
+        }
+        let (g, func_calls) = match g {
+            Some(g) => (g, 0),
+            None => {
+                let h = Scalar::default_epsilon().sqrt();
+                (self.differences(&block, h), block.len() + 1)
+            }
+        };
+        self.s = self.x.map(|_| Scalar::zero());
+        for &i in &block {
+            self.s[i] = -g[i];
+        }
+        func_calls
+    }
 }
 
 impl<Scalar, Dimension> DescentSearcher<Scalar, Dimension> for Gauss<Scalar, Dimension>
 where
     Scalar: RealField,
-    Dimension: DimName,
+    Dimension: Dim,
     DefaultAllocator: Allocator<Scalar, Dimension>,
 {
+    // the direction of the current block, zero outside of it
     fn S(&self) -> VectorN<Scalar, Dimension> {
-        let mut s = nalgebra::zero::<VectorN<Scalar, Dimension>>();
-        s[self.iters % Dimension::dim()] = Scalar::one();
-        s
+        self.s.clone()
     }
 }
 
@@ -64,15 +188,21 @@ impl<Scalar, Dimension> extremum_searcher::Search<VectorN<Scalar, Dimension>>
     for Gauss<Scalar, Dimension>
 where
     Scalar: RealField,
-    Dimension: DimName,
+    Dimension: Dim,
     DefaultAllocator: Allocator<Scalar, Dimension>,
 {
     fn iters(&self) -> usize {
         self.iters
     }
+    fn error(&self) -> Option<SearchError> {
+        self.error
+    }
     fn func_calls(&self) -> usize {
         self.func_calls
     }
+    fn derivative_calls(&self) -> usize {
+        self.derivative_calls
+    }
     fn x(&self) -> VectorN<Scalar, Dimension> {
         self.x.clone()
     }
@@ -84,37 +214,179 @@ where
 impl<Scalar, Dimension> Iterator for Gauss<Scalar, Dimension>
 where
     Scalar: RealField,
-    Dimension: DimName,
+    Dimension: Dim,
     DefaultAllocator: Allocator<Scalar, Dimension>,
 {
     type Item = IterationResult<VectorN<Scalar, Dimension>>;
     fn next(&mut self) -> Option<Self::Item> {
-        let is_extra =
-            if self.dx.iter().all(|xi| xi.abs() < self.eps) || self.iters >= self.max_iters {
-                true
-            } else {
-                false
-            };
+        if self.error.is_some() {
+            return None;
+        }
+        // the greedy rules always move along the block furthest from optimal, so a single short
+        // move is enough, the others need a short move along every block
+        let converged = match self.selection {
+            Selection::GaussSouthwell { .. } | Selection::FiniteDifference { .. } => {
+                self.dx.iter().all(|xi| xi.abs() < self.eps)
+            }
+            _ => self.moves.iter().all(|m| *m < self.eps),
+        };
+        let is_extra = converged || self.iters >= self.max_iters;
+        let (mut func_calls, derivative_calls, g) = self.select();
+        func_calls += self.direction(g);
+
+        // one line search over the whole block, both ways since the sign of `s` only descends
+        // for minimisation
         let x = self.x.clone();
         let f = self.f.clone();
         let s = self.S();
-        let lambda_result = one_dimension_searchers::search::Search::result(
-            Scalar::zero(),
+        let lambda_result = match line_searchers::search::Search::either_way(
             Arc::new(move |lambda| f(x.clone() + s.clone() * lambda)),
+            None,
             self.comparator,
-            one_dimension_searchers::search::Method::Fibonacci,
+            self.line_search.clone(),
+            Scalar::one(),
             self.eps,
             self.max_iters,
-        );
+        ) {
+            Ok(lambda_result) => lambda_result,
+            Err(error) => {
+                self.error = Some(error);
+                return None;
+            }
+        };
+        func_calls += lambda_result.func_calls();
         self.dx = self.S() * lambda_result.x();
         self.x += self.dx.clone();
+        self.moves[self.block] = self.dx.amax();
         self.iters += 1;
-        self.func_calls += lambda_result.func_calls();
-        Some(IterationResult::new(
-            self.x.clone(),
-            self.dx.clone(),
-            lambda_result.func_calls(),
-            is_extra,
-        ))
+        self.func_calls += func_calls;
+        self.derivative_calls += derivative_calls;
+        Some(
+            IterationResult::new(self.x.clone(), self.dx.clone(), func_calls, is_extra)
+                .with_derivative_calls(derivative_calls),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::searchers::descent_searchers::search::{Method, Search};
+    use crate::searchers::one_dimension_searchers;
+    use crate::searchers::one_dimension_searchers::search::Bracketing;
+    use nalgebra::{Vector3, U3};
+
+    type Function = Arc<dyn Fn(Vector3<f64>) -> f64>;
+    type Gradient = Arc<dyn Fn(Vector3<f64>) -> Vector3<f64>>;
+
+    fn quadratic() -> Function {
+        Arc::new(|x| {
+            (x[0] - 1.0).powi(2) + 10.0 * (x[1] + 2.0).powi(2) + (x[2] - 3.0).powi(2) + x[0] * x[2]
+        })
+    }
+
+    fn method(selection: Selection<f64, U3>, blocks: Option<Vec<Vec<usize>>>) -> Method<f64, U3> {
+        Method::Gauss {
+            line_search: line_searchers::search::Method::Exact {
+                method: one_dimension_searchers::search::Method::Brent,
+                bracketing: Bracketing::default(),
+            },
+            selection,
+            blocks,
+        }
+    }
+
+    // the minimum of `quadratic`
+    fn minimum() -> Vector3<f64> {
+        Vector3::new(-2.0 / 3.0, -2.0, 10.0 / 3.0)
+    }
+
+    #[test]
+    fn every_selection_minimises_a_quadratic() {
+        let df: Gradient = Arc::new(|x| {
+            Vector3::new(
+                2.0 * (x[0] - 1.0) + x[2],
+                20.0 * (x[1] + 2.0),
+                2.0 * (x[2] - 3.0) + x[0],
+            )
+        });
+        for selection in [
+            Selection::Cyclic,
+            Selection::GaussSouthwell { df },
+            Selection::FiniteDifference { h: 1e-7 },
+            Selection::Random { seed: 7 },
+            Selection::Permutation { seed: 7 },
+        ] {
+            let result = Search::Mnimimum(
+                Vector3::new(0.0, 0.0, 0.0),
+                quadratic(),
+                method(selection, None),
+                1e-9,
+                1000,
+            )
+            .unwrap();
+            assert!((result.x() - minimum()).norm() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn blocks_minimise_a_quadratic() {
+        let result = Search::Mnimimum(
+            Vector3::new(0.0, 0.0, 0.0),
+            quadratic(),
+            method(Selection::Cyclic, Some(vec![vec![0, 2], vec![1]])),
+            1e-9,
+            1000,
+        )
+        .unwrap();
+        assert!((result.x() - minimum()).norm() < 1e-4);
+    }
+
+    #[test]
+    fn a_block_is_moved_along_its_gradient_in_one_step() {
+        let mut search = Search::new(
+            Vector3::new(0.0, 0.0, 0.0),
+            quadratic(),
+            std::cmp::Ordering::Less,
+            method(Selection::Cyclic, Some(vec![vec![0, 2], vec![1]])),
+            1e-9,
+            100,
+        );
+        // the gradient at the origin is (-2, 40, -6), restricted to the first block
+        let dx = search.next().unwrap().dx();
+        assert_eq!(dx[1], 0.0);
+        assert!(dx[0] > 0.0 && (dx[2] / dx[0] - 3.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn permutation_is_reproducible_under_a_seed() {
+        let path = |seed| {
+            Search::new(
+                Vector3::new(0.0, 0.0, 0.0),
+                quadratic(),
+                std::cmp::Ordering::Less,
+                method(Selection::Permutation { seed }, None),
+                1e-9,
+                100,
+            )
+            .take(9)
+            .map(|r| r.x())
+            .collect::<Vec<_>>()
+        };
+        assert_eq!(path(3), path(3));
+    }
+
+    #[test]
+    fn invalid_blocks_are_reported() {
+        for blocks in [vec![], vec![vec![0, 1], vec![]], vec![vec![0, 3]]] {
+            let result = Search::Mnimimum(
+                Vector3::new(0.0, 0.0, 0.0),
+                quadratic(),
+                method(Selection::Cyclic, Some(blocks)),
+                1e-9,
+                100,
+            );
+            assert_eq!(result.err(), Some(SearchError::InvalidParameter("blocks")));
+        }
     }
 }
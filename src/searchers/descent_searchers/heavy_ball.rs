@@ -0,0 +1,209 @@
+use super::search::{DescentSearcher, Gradient, Restart};
+use crate::searchers::extremum_searcher;
+use crate::searchers::extremum_searcher::IterationResult;
+use nalgebra::{allocator::Allocator, DefaultAllocator, Dim, RealField, VectorN};
+use std::sync::Arc;
+
+pub struct HeavyBall<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: Dim,
+    DefaultAllocator: Allocator<Scalar, Dimension>,
+{
+    x: VectorN<Scalar, Dimension>,
+    dx: VectorN<Scalar, Dimension>,
+    g: VectorN<Scalar, Dimension>,
+    // the previous step, zero before the first one and after a restart
+    momentum: VectorN<Scalar, Dimension>,
+    // value of `sign * f` at `x`, only known when restarting on the function value
+    fx: Option<Scalar>,
+    sign: Scalar,
+    func_calls: usize,
+    derivative_calls: usize,
+    iters: usize,
+    f: Arc<dyn Fn(VectorN<Scalar, Dimension>) -> Scalar>,
+    df: Gradient<Scalar, Dimension>,
+    alpha: Scalar,
+    beta: Scalar,
+    restart: Option<Restart>,
+    eps: Scalar,
+    max_iters: usize,
+}
+
+impl<Scalar, Dimension> HeavyBall<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: Dim,
+    DefaultAllocator: Allocator<Scalar, Dimension>,
+{
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        x0: VectorN<Scalar, Dimension>,
+        f: Arc<dyn Fn(VectorN<Scalar, Dimension>) -> Scalar>,
+        df: Gradient<Scalar, Dimension>,
+        comparator: std::cmp::Ordering,
+        alpha: Scalar,
+        beta: Scalar,
+        restart: Option<Restart>,
+        eps: Scalar,
+        max_iters: usize,
+    ) -> Self {
+        let sign = if comparator == std::cmp::Ordering::Less {
+            Scalar::one()
+        } else {
+            -Scalar::one()
+        };
+        let g = df(x0.clone()) * sign;
+        let (fx, func_calls) = match restart {
+            Some(Restart::FunctionValue) => (Some(f(x0.clone()) * sign), 1),
+            _ => (None, 0),
+        };
+        Self {
+            dx: x0.map(|_| Scalar::max_value()),
+            momentum: x0.map(|_| Scalar::zero()),
+            x: x0,
+            g,
+            fx,
+            sign,
+            f,
+            df,
+            alpha,
+            beta,
+            restart,
+            eps,
+            iters: 0,
+            func_calls,
+            derivative_calls: 1,
+            max_iters,
+        }
+    }
+}
+
+impl<Scalar, Dimension> DescentSearcher<Scalar, Dimension> for HeavyBall<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: Dim,
+    DefaultAllocator: Allocator<Scalar, Dimension>,
+{
+    fn S(&self) -> VectorN<Scalar, Dimension> {
+        -self.g.clone()
+    }
+}
+
+impl<Scalar, Dimension> extremum_searcher::Search<VectorN<Scalar, Dimension>>
+    for HeavyBall<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: Dim,
+    DefaultAllocator: Allocator<Scalar, Dimension>,
+{
+    fn iters(&self) -> usize {
+        self.iters
+    }
+    fn func_calls(&self) -> usize {
+        self.func_calls
+    }
+    fn derivative_calls(&self) -> usize {
+        self.derivative_calls
+    }
+    fn x(&self) -> VectorN<Scalar, Dimension> {
+        self.x.clone()
+    }
+    fn dx(&self) -> VectorN<Scalar, Dimension> {
+        self.dx.clone()
+    }
+}
+
+impl<Scalar, Dimension> Iterator for HeavyBall<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: Dim,
+    DefaultAllocator: Allocator<Scalar, Dimension>,
+{
+    type Item = IterationResult<VectorN<Scalar, Dimension>>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let is_extra = self.g.norm() < self.eps
+            || self.dx.iter().all(|xi| xi.abs() < self.eps)
+            || self.iters >= self.max_iters;
+        self.dx = self.S() * self.alpha + &self.momentum * self.beta;
+        self.x += self.dx.clone();
+        self.iters += 1;
+        self.g = (self.df)(self.x.clone()) * self.sign;
+
+        // the next step starts without momentum when this one went uphill
+        let (restart, func_calls) = match self.restart {
+            Some(Restart::FunctionValue) => {
+                let fx = (self.f)(self.x.clone()) * self.sign;
+                let restart = matches!(self.fx, Some(_fx) if fx > _fx);
+                self.fx = Some(fx);
+                (restart, 1)
+            }
+            Some(Restart::Gradient) => (self.g.dot(&self.dx) > Scalar::zero(), 0),
+            None => (false, 0),
+        };
+        self.momentum = if restart {
+            self.dx.map(|_| Scalar::zero())
+        } else {
+            self.dx.clone()
+        };
+
+        self.func_calls += func_calls;
+        self.derivative_calls += 1;
+        Some(
+            IterationResult::new(self.x.clone(), self.dx.clone(), func_calls, is_extra)
+                .with_derivative_calls(1),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::searchers::descent_searchers::search::{Method, Search};
+    use nalgebra::{Vector2, U2};
+
+    type Function = Arc<dyn Fn(Vector2<f64>) -> f64>;
+
+    fn quadratic() -> (Function, Gradient<f64, U2>) {
+        (
+            Arc::new(|x| 10.0 * (x[0] - 1.0).powi(2) + 0.5 * (x[1] + 2.0).powi(2)),
+            Arc::new(|x| Vector2::new(20.0 * (x[0] - 1.0), x[1] + 2.0)),
+        )
+    }
+
+    fn iters(beta: f64, restart: Option<Restart>) -> (Vector2<f64>, usize) {
+        let (f, df) = quadratic();
+        let result = Search::Mnimimum(
+            Vector2::new(-3.0, 4.0),
+            f,
+            Method::HeavyBall {
+                df,
+                alpha: 0.04,
+                beta,
+                restart,
+            },
+            1e-10,
+            100000,
+        )
+        .unwrap();
+        (result.x(), result.iters())
+    }
+
+    #[test]
+    fn every_restart_minimises_a_quadratic() {
+        for restart in [None, Some(Restart::FunctionValue), Some(Restart::Gradient)] {
+            let (x, _) = iters(0.5, restart);
+            assert!((x - Vector2::new(1.0, -2.0)).norm() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn restarts_damp_a_heavy_momentum() {
+        let (_, plain) = iters(0.99, None);
+        for restart in [Restart::FunctionValue, Restart::Gradient] {
+            let (x, restarted) = iters(0.99, Some(restart));
+            assert!((x - Vector2::new(1.0, -2.0)).norm() < 1e-6);
+            assert!(restarted < plain);
+        }
+    }
+}
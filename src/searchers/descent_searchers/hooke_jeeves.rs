@@ -0,0 +1,252 @@
+use super::search::DescentSearcher;
+use crate::searchers::extremum_searcher;
+use crate::searchers::extremum_searcher::IterationResult;
+use nalgebra::{allocator::Allocator, DefaultAllocator, Dim, RealField, VectorN};
+use std::sync::Arc;
+
+pub struct HookeJeeves<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: Dim,
+    DefaultAllocator: Allocator<Scalar, Dimension>,
+{
+    x: VectorN<Scalar, Dimension>,
+    fx: Scalar,
+    // step sizes along the coordinates, reported as `dx`
+    h: VectorN<Scalar, Dimension>,
+    // the last pattern move, the next exploration starts from `x + s`
+    s: Option<VectorN<Scalar, Dimension>>,
+    func_calls: usize,
+    iters: usize,
+    f: Arc<dyn Fn(VectorN<Scalar, Dimension>) -> Scalar>,
+    reduction: Scalar,
+    eps: Scalar,
+    max_iters: usize,
+    comparator: std::cmp::Ordering,
+}
+
+impl<Scalar, Dimension> HookeJeeves<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: Dim,
+    DefaultAllocator: Allocator<Scalar, Dimension>,
+{
+    pub fn new(
+        x0: VectorN<Scalar, Dimension>,
+        f: Arc<dyn Fn(VectorN<Scalar, Dimension>) -> Scalar>,
+        comparator: std::cmp::Ordering,
+        step: Scalar,
+        reduction: Scalar,
+        eps: Scalar,
+        max_iters: usize,
+    ) -> Self {
+        Self {
+            fx: f(x0.clone()),
+            h: x0.map(|_| step),
+            x: x0,
+            s: None,
+            f,
+            reduction,
+            eps,
+            iters: 0,
+            func_calls: 1,
+            max_iters,
+            comparator,
+        }
+    }
+
+    fn is_better(&self, f1: Scalar, f2: Scalar) -> bool {
+        f1.partial_cmp(&f2) == Some(self.comparator)
+    }
+
+    // exploratory moves along every coordinate, returns the point found with its value and the calls spent
+    fn explore(
+        &self,
+        mut x: VectorN<Scalar, Dimension>,
+        mut fx: Scalar,
+    ) -> (VectorN<Scalar, Dimension>, Scalar, usize) {
+        let mut func_calls = 0;
+        for i in 0..x.len() {
+            for &h in [self.h[i], -self.h[i]].iter() {
+                let mut _x = x.clone();
+                _x[i] += h;
+                let _fx = (self.f)(_x.clone());
+                func_calls += 1;
+                if self.is_better(_fx, fx) {
+                    x = _x;
+                    fx = _fx;
+                    break;
+                }
+            }
+        }
+        (x, fx, func_calls)
+    }
+}
+
+impl<Scalar, Dimension> DescentSearcher<Scalar, Dimension> for HookeJeeves<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: Dim,
+    DefaultAllocator: Allocator<Scalar, Dimension>,
+{
+    fn S(&self) -> VectorN<Scalar, Dimension> {
+        match &self.s {
+            Some(s) => s.clone(),
+            None => self.x.map(|_| Scalar::zero()),
+        }
+    }
+}
+
+impl<Scalar, Dimension> extremum_searcher::Search<VectorN<Scalar, Dimension>>
+    for HookeJeeves<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: Dim,
+    DefaultAllocator: Allocator<Scalar, Dimension>,
+{
+    fn iters(&self) -> usize {
+        self.iters
+    }
+    fn func_calls(&self) -> usize {
+        self.func_calls
+    }
+    fn x(&self) -> VectorN<Scalar, Dimension> {
+        self.x.clone()
+    }
+    fn dx(&self) -> VectorN<Scalar, Dimension> {
+        self.h.clone()
+    }
+}
+
+impl<Scalar, Dimension> Iterator for HookeJeeves<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: Dim,
+    DefaultAllocator: Allocator<Scalar, Dimension>,
+{
+    type Item = IterationResult<VectorN<Scalar, Dimension>>;
+    fn next(&mut self) -> Option<Self::Item> {
+        // nothing is evaluated once done, so every call made is counted in the result
+        if self.h.iter().all(|hi| hi.abs() < self.eps) || self.iters >= self.max_iters {
+            return Some(IterationResult::new(
+                self.x.clone(),
+                self.h.clone(),
+                0,
+                true,
+            ));
+        }
+        let mut func_calls = 0;
+        let from_base = self.s.is_none();
+        let (start, f_start) = match self.s.take() {
+            Some(s) => {
+                let pattern = self.x.clone() + s;
+                func_calls += 1;
+                (pattern.clone(), (self.f)(pattern))
+            }
+            None => (self.x.clone(), self.fx),
+        };
+        let (x, fx, calls) = self.explore(start, f_start);
+        func_calls += calls;
+        if self.is_better(fx, self.fx) {
+            self.s = Some(&x - &self.x);
+            self.x = x;
+            self.fx = fx;
+        } else if from_base {
+            // the exploration around the base point failed, so the steps are too large
+            self.h *= self.reduction;
+        }
+        self.iters += 1;
+        self.func_calls += func_calls;
+        Some(IterationResult::new(
+            self.x.clone(),
+            self.h.clone(),
+            func_calls,
+            false,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::searchers::descent_searchers::search::{Method, Search};
+    use crate::searchers::extremum_searcher::Search as _;
+    use nalgebra::Vector2;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    fn quadratic(x: Vector2<f64>) -> f64 {
+        (x[0] - 1.0).powi(2) + 10.0 * (x[1] + 2.0).powi(2) + x[0] * x[1]
+    }
+
+    #[test]
+    fn minimises_the_rosenbrock_function() {
+        let result = Search::Mnimimum(
+            Vector2::new(-1.2, 1.0),
+            Arc::new(|x: Vector2<f64>| 100.0 * (x[1] - x[0] * x[0]).powi(2) + (1.0 - x[0]).powi(2)),
+            Method::HookeJeeves {
+                step: 0.5,
+                reduction: 0.5,
+            },
+            1e-9,
+            100000,
+        )
+        .unwrap();
+        assert!((result.x() - Vector2::new(1.0, 1.0)).norm() < 1e-4);
+    }
+
+    #[test]
+    fn counts_every_function_call() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let _calls = calls.clone();
+        let mut searcher = HookeJeeves::new(
+            Vector2::new(3.0, 3.0),
+            Arc::new(move |x| {
+                _calls.fetch_add(1, Ordering::Relaxed);
+                quadratic(x)
+            }),
+            std::cmp::Ordering::Less,
+            1.0,
+            0.5,
+            1e-9,
+            1000,
+        );
+        let result = searcher.result();
+        assert_eq!(result.func_calls(), calls.load(Ordering::Relaxed));
+        assert_eq!(searcher.func_calls(), calls.load(Ordering::Relaxed));
+    }
+
+    #[test]
+    fn step_size_is_reported_as_dx_and_reduced_at_a_minimum() {
+        // the minimum of `quadratic` is at (80 / 39, -82 / 39), a step of 1 overshoots it
+        let mut searcher = HookeJeeves::new(
+            Vector2::new(80.0 / 39.0, -82.0 / 39.0),
+            Arc::new(quadratic),
+            std::cmp::Ordering::Less,
+            1.0,
+            0.25,
+            1e-9,
+            1000,
+        );
+        let r = searcher.next().unwrap();
+        assert_eq!(r.func_calls(), 4);
+        assert_eq!(r.dx(), Vector2::new(0.25, 0.25));
+        assert_eq!(searcher.dx(), Vector2::new(0.25, 0.25));
+    }
+
+    #[test]
+    fn a_successful_exploration_sets_the_pattern_move() {
+        let mut searcher = HookeJeeves::new(
+            Vector2::new(3.0, 3.0),
+            Arc::new(quadratic),
+            std::cmp::Ordering::Less,
+            0.5,
+            0.5,
+            1e-9,
+            1000,
+        );
+        let x0 = searcher.x();
+        searcher.next().unwrap();
+        assert_eq!(searcher.S(), searcher.x() - x0);
+        assert_ne!(searcher.S(), Vector2::zeros());
+    }
+}
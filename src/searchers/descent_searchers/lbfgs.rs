@@ -0,0 +1,328 @@
+use super::search::{DescentSearcher, Gradient, Scaling};
+use crate::searchers::extremum_searcher;
+use crate::searchers::extremum_searcher::{IterationResult, SearchError};
+use crate::searchers::line_searchers;
+use nalgebra::{allocator::Allocator, DefaultAllocator, Dim, RealField, VectorN};
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+type Correction<Scalar, Dimension> = (
+    VectorN<Scalar, Dimension>,
+    VectorN<Scalar, Dimension>,
+    Scalar,
+);
+
+pub struct LBfgs<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: Dim,
+    DefaultAllocator: Allocator<Scalar, Dimension>,
+{
+    x: VectorN<Scalar, Dimension>,
+    dx: VectorN<Scalar, Dimension>,
+    g: VectorN<Scalar, Dimension>,
+    // (s, y, 1 / s'y), the newest pair is at the back
+    history: VecDeque<Correction<Scalar, Dimension>>,
+    memory: usize,
+    scaling: Scaling<Scalar>,
+    sign: Scalar,
+    func_calls: usize,
+    derivative_calls: usize,
+    iters: usize,
+    error: Option<SearchError>,
+    f: Arc<dyn Fn(VectorN<Scalar, Dimension>) -> Scalar>,
+    df: Gradient<Scalar, Dimension>,
+    eps: Scalar,
+    max_iters: usize,
+    comparator: std::cmp::Ordering,
+    line_search: line_searchers::search::Method<Scalar>,
+}
+
+impl<Scalar, Dimension> LBfgs<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: Dim,
+    DefaultAllocator: Allocator<Scalar, Dimension>,
+{
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        x0: VectorN<Scalar, Dimension>,
+        f: Arc<dyn Fn(VectorN<Scalar, Dimension>) -> Scalar>,
+        df: Gradient<Scalar, Dimension>,
+        comparator: std::cmp::Ordering,
+        line_search: line_searchers::search::Method<Scalar>,
+        memory: usize,
+        scaling: Scaling<Scalar>,
+        eps: Scalar,
+        max_iters: usize,
+    ) -> Self {
+        // the pairs are collected for `sign * f`, so the implicit metric stays positive definite
+        let sign = if comparator == std::cmp::Ordering::Less {
+            Scalar::one()
+        } else {
+            -Scalar::one()
+        };
+        let g = df(x0.clone()) * sign;
+        Self {
+            dx: x0.map(|_| Scalar::max_value()),
+            x: x0,
+            g,
+            history: VecDeque::with_capacity(memory),
+            memory,
+            scaling,
+            sign,
+            f,
+            df,
+            eps,
+            iters: 0,
+            error: None,
+            func_calls: 0,
+            derivative_calls: 1,
+            max_iters,
+            comparator,
+            line_search,
+        }
+    }
+
+    fn gamma(&self) -> Scalar {
+        match (&self.scaling, self.history.back()) {
+            (Scaling::Identity, _) | (Scaling::ShannoPhua, None) => Scalar::one(),
+            (Scaling::ShannoPhua, Some((_, y, rho))) => Scalar::one() / (*rho * y.norm_squared()),
+            (Scaling::Constant(gamma), _) => *gamma,
+        }
+    }
+}
+
+impl<Scalar, Dimension> DescentSearcher<Scalar, Dimension> for LBfgs<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: Dim,
+    DefaultAllocator: Allocator<Scalar, Dimension>,
+{
+    // two-loop recursion
+    fn S(&self) -> VectorN<Scalar, Dimension> {
+        let mut q = self.g.clone();
+        let mut alpha = Vec::with_capacity(self.history.len());
+        for (s, y, rho) in self.history.iter().rev() {
+            let a = *rho * s.dot(&q);
+            q.axpy(-a, y, Scalar::one());
+            alpha.push(a);
+        }
+        let mut r = q * self.gamma();
+        for ((s, y, rho), a) in self.history.iter().zip(alpha.into_iter().rev()) {
+            let b = *rho * y.dot(&r);
+            r.axpy(a - b, s, Scalar::one());
+        }
+        -r
+    }
+}
+
+impl<Scalar, Dimension> extremum_searcher::Search<VectorN<Scalar, Dimension>>
+    for LBfgs<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: Dim,
+    DefaultAllocator: Allocator<Scalar, Dimension>,
+{
+    fn iters(&self) -> usize {
+        self.iters
+    }
+    fn error(&self) -> Option<SearchError> {
+        self.error
+    }
+    fn func_calls(&self) -> usize {
+        self.func_calls
+    }
+    fn derivative_calls(&self) -> usize {
+        self.derivative_calls
+    }
+    fn x(&self) -> VectorN<Scalar, Dimension> {
+        self.x.clone()
+    }
+    fn dx(&self) -> VectorN<Scalar, Dimension> {
+        self.dx.clone()
+    }
+}
+
+impl<Scalar, Dimension> Iterator for LBfgs<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: Dim,
+    DefaultAllocator: Allocator<Scalar, Dimension>,
+{
+    type Item = IterationResult<VectorN<Scalar, Dimension>>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let is_extra = self.g.norm() < self.eps
+            || self.dx.iter().all(|xi| xi.abs() < self.eps)
+            || self.iters >= self.max_iters;
+        // nothing is searched once the search is over, the direction may no longer descend
+        if is_extra {
+            return Some(IterationResult::new(
+                self.x.clone(),
+                self.x.map(|_| Scalar::zero()),
+                0,
+                true,
+            ));
+        }
+        let x = self.x.clone();
+        let f = self.f.clone();
+        let df = self.df.clone();
+        let s = self.S();
+        let (_x, _s) = (x.clone(), s.clone());
+        let direction = s.clone();
+        let lambda_result = match line_searchers::search::Search::result(
+            Arc::new(move |lambda| f(x.clone() + s.clone() * lambda)),
+            Some(Arc::new(move |lambda| {
+                df(_x.clone() + _s.clone() * lambda).dot(&_s)
+            })),
+            self.comparator,
+            self.line_search.clone(),
+            Scalar::one(),
+            self.eps,
+            self.max_iters,
+        ) {
+            Ok(lambda_result) => lambda_result,
+            Err(error) => {
+                self.error = Some(error);
+                return None;
+            }
+        };
+        self.dx = direction * lambda_result.x();
+        self.x += self.dx.clone();
+        self.iters += 1;
+        let dg = (self.df)(self.x.clone()) * self.sign - self.g.clone();
+        self.g += dg.clone();
+
+        // pairs violating the curvature condition would break positive definiteness
+        let sy = self.dx.dot(&dg);
+        if self.memory > 0 && sy > Scalar::default_epsilon() * self.dx.norm() * dg.norm() {
+            if self.history.len() == self.memory {
+                self.history.pop_front();
+            }
+            self.history
+                .push_back((self.dx.clone(), dg, Scalar::one() / sy));
+        }
+
+        self.func_calls += lambda_result.func_calls();
+        self.derivative_calls += lambda_result.derivative_calls() + 1;
+        Some(
+            IterationResult::new(
+                self.x.clone(),
+                self.dx.clone(),
+                lambda_result.func_calls(),
+                is_extra,
+            )
+            .with_derivative_calls(lambda_result.derivative_calls() + 1),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::searchers::descent_searchers::search::{Method, Search};
+    use crate::searchers::one_dimension_searchers;
+    use crate::searchers::one_dimension_searchers::search::Bracketing;
+    use nalgebra::{DVector, Dynamic};
+
+    type Function = Arc<dyn Fn(DVector<f64>) -> f64>;
+
+    // the extended rosenbrock function, its minimum is at all ones
+    fn rosenbrock() -> (Function, Gradient<f64, Dynamic>) {
+        (
+            Arc::new(|x| {
+                (0..x.len() / 2)
+                    .map(|i| {
+                        let (a, b) = (x[2 * i], x[2 * i + 1]);
+                        100.0 * (b - a * a).powi(2) + (1.0 - a).powi(2)
+                    })
+                    .sum()
+            }),
+            Arc::new(|x| {
+                let mut g = DVector::zeros(x.len());
+                for i in 0..x.len() / 2 {
+                    let (a, b) = (x[2 * i], x[2 * i + 1]);
+                    g[2 * i] = -400.0 * a * (b - a * a) - 2.0 * (1.0 - a);
+                    g[2 * i + 1] = 200.0 * (b - a * a);
+                }
+                g
+            }),
+        )
+    }
+
+    fn x0(n: usize) -> DVector<f64> {
+        DVector::from_fn(n, |i, _| if i % 2 == 0 { -1.2 } else { 1.0 })
+    }
+
+    fn line_search() -> line_searchers::search::Method<f64> {
+        line_searchers::search::Method::Exact {
+            method: one_dimension_searchers::search::Method::Brent,
+            bracketing: Bracketing::default(),
+        }
+    }
+
+    #[test]
+    fn every_scaling_minimises_a_high_dimensional_problem() {
+        for scaling in [
+            Scaling::Identity,
+            Scaling::ShannoPhua,
+            Scaling::Constant(0.01),
+        ] {
+            let (f, df) = rosenbrock();
+            let result = Search::Mnimimum(
+                x0(1000),
+                f,
+                Method::LBfgs {
+                    df,
+                    line_search: line_search(),
+                    memory: 5,
+                    scaling,
+                },
+                1e-9,
+                1000,
+            )
+            .unwrap();
+            assert!((result.x() - DVector::from_element(1000, 1.0)).amax() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn history_is_limited_to_the_memory() {
+        let (f, df) = rosenbrock();
+        let mut searcher = LBfgs::new(
+            x0(10),
+            f,
+            df,
+            std::cmp::Ordering::Less,
+            line_search(),
+            3,
+            Scaling::ShannoPhua,
+            1e-9,
+            1000,
+        );
+        for _ in 0..10 {
+            searcher.next().unwrap();
+            assert!(searcher.history.len() <= 3);
+        }
+        assert_eq!(searcher.history.len(), 3);
+    }
+
+    #[test]
+    fn without_memory_the_direction_is_the_scaled_antigradient() {
+        let (f, df) = rosenbrock();
+        let mut searcher = LBfgs::new(
+            x0(4),
+            f,
+            df.clone(),
+            std::cmp::Ordering::Less,
+            line_search(),
+            0,
+            Scaling::Constant(0.5),
+            1e-9,
+            1000,
+        );
+        searcher.next().unwrap();
+        assert!(searcher.history.is_empty());
+        assert!((searcher.S() + df(searcher.x.clone()) * 0.5).norm() < 1e-9);
+    }
+}
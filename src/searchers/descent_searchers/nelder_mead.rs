@@ -0,0 +1,349 @@
+use super::search::{Coefficients, DescentSearcher};
+use crate::searchers::extremum_searcher;
+use crate::searchers::extremum_searcher::IterationResult;
+use nalgebra::{allocator::Allocator, DefaultAllocator, Dim, RealField, VectorN};
+use std::sync::Arc;
+
+pub struct NelderMead<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: Dim,
+    DefaultAllocator: Allocator<Scalar, Dimension>,
+{
+    // vertices with the values of `sign * f`, sorted from the best one
+    simplex: Vec<(VectorN<Scalar, Dimension>, Scalar)>,
+    sign: Scalar,
+    reflection: Scalar,
+    expansion: Scalar,
+    contraction: Scalar,
+    shrink: Scalar,
+    func_calls: usize,
+    iters: usize,
+    f: Arc<dyn Fn(VectorN<Scalar, Dimension>) -> Scalar>,
+    eps: Scalar,
+    max_iters: usize,
+}
+
+impl<Scalar, Dimension> NelderMead<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: Dim,
+    DefaultAllocator: Allocator<Scalar, Dimension>,
+{
+    pub fn new(
+        x0: VectorN<Scalar, Dimension>,
+        f: Arc<dyn Fn(VectorN<Scalar, Dimension>) -> Scalar>,
+        comparator: std::cmp::Ordering,
+        coefficients: Coefficients<Scalar>,
+        size: Scalar,
+        eps: Scalar,
+        max_iters: usize,
+    ) -> Self {
+        let sign = if comparator == std::cmp::Ordering::Less {
+            Scalar::one()
+        } else {
+            -Scalar::one()
+        };
+        let n = x0.len();
+        let (reflection, expansion, contraction, shrink) = match coefficients {
+            Coefficients::Fixed {
+                reflection,
+                expansion,
+                contraction,
+                shrink,
+            } => (reflection, expansion, contraction, shrink),
+            Coefficients::Adaptive if n < 2 => {
+                let two = Scalar::from_i8(2).unwrap();
+                (Scalar::one(), two, Scalar::one() / two, Scalar::one() / two)
+            }
+            Coefficients::Adaptive => {
+                let n = Scalar::from_usize(n).unwrap();
+                let two = Scalar::from_i8(2).unwrap();
+                (
+                    Scalar::one(),
+                    Scalar::one() + two / n,
+                    Scalar::from_f64(0.75).unwrap() - Scalar::one() / (two * n),
+                    Scalar::one() - Scalar::one() / n,
+                )
+            }
+        };
+        let mut simplex = Vec::with_capacity(n + 1);
+        simplex.push((x0.clone(), f(x0.clone()) * sign));
+        for i in 0..n {
+            let mut x = x0.clone();
+            x[i] += size;
+            let fx = f(x.clone()) * sign;
+            simplex.push((x, fx));
+        }
+        let mut searcher = Self {
+            simplex,
+            sign,
+            reflection,
+            expansion,
+            contraction,
+            shrink,
+            f,
+            eps,
+            iters: 0,
+            func_calls: n + 1,
+            max_iters,
+        };
+        searcher.sort();
+        searcher
+    }
+
+    // the vertices of the simplex, sorted from the best one
+    pub fn vertices(&self) -> Vec<VectorN<Scalar, Dimension>> {
+        self.simplex.iter().map(|(x, _)| x.clone()).collect()
+    }
+
+    // vertices with a NaN value are ordered last, so they are replaced first
+    fn sort(&mut self) {
+        let is_nan = |f: &Scalar| f.partial_cmp(f).is_none();
+        self.simplex
+            .sort_by(|(_, f1), (_, f2)| match (is_nan(f1), is_nan(f2)) {
+                (false, true) => std::cmp::Ordering::Less,
+                (true, false) => std::cmp::Ordering::Greater,
+                _ => f1.partial_cmp(f2).unwrap_or(std::cmp::Ordering::Equal),
+            });
+    }
+
+    fn value(&self, x: &VectorN<Scalar, Dimension>) -> Scalar {
+        (self.f)(x.clone()) * self.sign
+    }
+
+    fn centroid(&self) -> VectorN<Scalar, Dimension> {
+        let n = self.simplex.len() - 1;
+        self.simplex[..n]
+            .iter()
+            .fold(self.simplex[0].0.map(|_| Scalar::zero()), |c, (x, _)| c + x)
+            / Scalar::from_usize(n).unwrap()
+    }
+
+    fn diameter(&self) -> Scalar {
+        let best = &self.simplex[0].0;
+        self.simplex
+            .iter()
+            .map(|(x, _)| (x - best).norm())
+            .fold(Scalar::zero(), |a, b| a.max(b))
+    }
+
+    fn spread(&self) -> Scalar {
+        self.simplex[self.simplex.len() - 1].1 - self.simplex[0].1
+    }
+
+    fn farthest(&self) -> VectorN<Scalar, Dimension> {
+        let best = &self.simplex[0].0;
+        self.simplex
+            .iter()
+            .map(|(x, _)| x - best)
+            .fold(best.map(|_| Scalar::zero()), |a, b| {
+                if b.norm() > a.norm() {
+                    b
+                } else {
+                    a
+                }
+            })
+    }
+}
+
+impl<Scalar, Dimension> DescentSearcher<Scalar, Dimension> for NelderMead<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: Dim,
+    DefaultAllocator: Allocator<Scalar, Dimension>,
+{
+    // the reflection direction, away from the worst vertex
+    fn S(&self) -> VectorN<Scalar, Dimension> {
+        self.centroid() - &self.simplex[self.simplex.len() - 1].0
+    }
+}
+
+impl<Scalar, Dimension> extremum_searcher::Search<VectorN<Scalar, Dimension>>
+    for NelderMead<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: Dim,
+    DefaultAllocator: Allocator<Scalar, Dimension>,
+{
+    fn iters(&self) -> usize {
+        self.iters
+    }
+    fn func_calls(&self) -> usize {
+        self.func_calls
+    }
+    fn x(&self) -> VectorN<Scalar, Dimension> {
+        self.simplex[0].0.clone()
+    }
+    fn dx(&self) -> VectorN<Scalar, Dimension> {
+        self.farthest()
+    }
+}
+
+impl<Scalar, Dimension> Iterator for NelderMead<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: Dim,
+    DefaultAllocator: Allocator<Scalar, Dimension>,
+{
+    type Item = IterationResult<VectorN<Scalar, Dimension>>;
+    fn next(&mut self) -> Option<Self::Item> {
+        // nothing is evaluated once done, so every call made is counted in the result
+        let is_extra = (self.diameter() < self.eps && self.spread() < self.eps)
+            || self.iters >= self.max_iters;
+        if is_extra {
+            return Some(IterationResult::new(
+                self.simplex[0].0.clone(),
+                self.farthest(),
+                0,
+                true,
+            ));
+        }
+
+        let n = self.simplex.len() - 1;
+        let c = self.centroid();
+        let (f_best, f_second) = (self.simplex[0].1, self.simplex[n - 1].1);
+        let (worst, f_worst) = self.simplex[n].clone();
+        let mut func_calls = 0;
+
+        let reflected = &c + (&c - &worst) * self.reflection;
+        let f_reflected = self.value(&reflected);
+        func_calls += 1;
+        let accepted = if f_reflected < f_best {
+            let expanded = &c + (&reflected - &c) * self.expansion;
+            let f_expanded = self.value(&expanded);
+            func_calls += 1;
+            if f_expanded < f_reflected {
+                Some((expanded, f_expanded))
+            } else {
+                Some((reflected, f_reflected))
+            }
+        } else if f_reflected < f_second {
+            Some((reflected, f_reflected))
+        } else {
+            let (contracted, bound) = if f_reflected < f_worst {
+                (&c + (&reflected - &c) * self.contraction, f_reflected)
+            } else {
+                (&c + (&worst - &c) * self.contraction, f_worst)
+            };
+            let f_contracted = self.value(&contracted);
+            func_calls += 1;
+            if f_contracted < bound {
+                Some((contracted, f_contracted))
+            } else {
+                None
+            }
+        };
+        match accepted {
+            Some(vertex) => self.simplex[n] = vertex,
+            None => {
+                let best = self.simplex[0].0.clone();
+                for i in 1..=n {
+                    let x = &best + (&self.simplex[i].0 - &best) * self.shrink;
+                    let fx = self.value(&x);
+                    self.simplex[i] = (x, fx);
+                }
+                func_calls += n;
+            }
+        }
+        self.sort();
+
+        self.iters += 1;
+        self.func_calls += func_calls;
+        Some(IterationResult::new(
+            self.simplex[0].0.clone(),
+            self.farthest(),
+            func_calls,
+            false,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::searchers::descent_searchers::search;
+    use crate::searchers::extremum_searcher::Search;
+    use nalgebra::{Vector1, Vector2, U2};
+
+    fn rosenbrock() -> Arc<dyn Fn(Vector2<f64>) -> f64> {
+        Arc::new(|x| 100.0 * (x[1] - x[0] * x[0]).powi(2) + (1.0 - x[0]).powi(2))
+    }
+
+    #[test]
+    fn minimises_the_rosenbrock_function() {
+        for coefficients in [
+            Coefficients::Fixed {
+                reflection: 1.0,
+                expansion: 2.0,
+                contraction: 0.5,
+                shrink: 0.5,
+            },
+            Coefficients::Adaptive,
+        ] {
+            let result = search::Search::<f64, U2>::Mnimimum(
+                Vector2::new(-1.2, 1.0),
+                rosenbrock(),
+                search::Method::NelderMead {
+                    coefficients,
+                    size: 0.5,
+                },
+                1e-10,
+                10000,
+            )
+            .unwrap();
+            assert!((result.x() - Vector2::new(1.0, 1.0)).norm() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn every_iteration_reports_the_simplex() {
+        let mut searcher = NelderMead::new(
+            Vector2::new(-1.2, 1.0),
+            rosenbrock(),
+            std::cmp::Ordering::Less,
+            Coefficients::Adaptive,
+            0.5,
+            1e-10,
+            100,
+        );
+        for _ in 0..10 {
+            let r = searcher.next().unwrap();
+            let simplex = searcher.vertices();
+            assert_eq!(simplex.len(), 3);
+            // the best vertex comes first and is the reported point
+            assert_eq!(simplex[0], r.x());
+        }
+    }
+
+    #[test]
+    fn adaptive_coefficients_still_shrink_in_one_dimension() {
+        let searcher = NelderMead::new(
+            Vector1::new(3.0),
+            Arc::new(|x: Vector1<f64>| (x[0] - 1.0).powi(2)),
+            std::cmp::Ordering::Less,
+            Coefficients::Adaptive,
+            1.0,
+            1e-10,
+            1000,
+        );
+        assert_eq!(searcher.shrink, 0.5);
+        let mut searcher = searcher;
+        assert!((searcher.result().x()[0] - 1.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn nan_vertices_are_ordered_last() {
+        let searcher = NelderMead::new(
+            Vector2::new(0.0, 0.0),
+            Arc::new(|x: Vector2<f64>| if x[0] > 0.0 { f64::NAN } else { x.norm() }),
+            std::cmp::Ordering::Less,
+            Coefficients::Adaptive,
+            1.0,
+            1e-10,
+            100,
+        );
+        assert!(searcher.simplex[2].1.is_nan());
+        assert_eq!(searcher.simplex[0].0, Vector2::new(0.0, 0.0));
+    }
+}
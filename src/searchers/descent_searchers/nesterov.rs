@@ -0,0 +1,305 @@
+use super::search::{DescentSearcher, Gradient, LipschitzEstimate, Restart};
+use crate::searchers::extremum_searcher;
+use crate::searchers::extremum_searcher::{IterationResult, SearchError};
+use nalgebra::{allocator::Allocator, DefaultAllocator, Dim, RealField, VectorN};
+use std::sync::Arc;
+
+pub struct Nesterov<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: Dim,
+    DefaultAllocator: Allocator<Scalar, Dimension>,
+{
+    x: VectorN<Scalar, Dimension>,
+    dx: VectorN<Scalar, Dimension>,
+    // extrapolated point the gradient step is taken from, and the gradient there
+    y: VectorN<Scalar, Dimension>,
+    g: VectorN<Scalar, Dimension>,
+    // value of `sign * f` at `x`, only known when backtracking or restarting on the function value
+    fx: Option<Scalar>,
+    t: Scalar,
+    // current estimate of the lipschitz constant, the step is `1 / l`
+    l: Scalar,
+    sign: Scalar,
+    func_calls: usize,
+    derivative_calls: usize,
+    iters: usize,
+    error: Option<SearchError>,
+    f: Arc<dyn Fn(VectorN<Scalar, Dimension>) -> Scalar>,
+    df: Gradient<Scalar, Dimension>,
+    lipschitz: LipschitzEstimate<Scalar>,
+    restart: Option<Restart>,
+    eps: Scalar,
+    max_iters: usize,
+}
+
+impl<Scalar, Dimension> Nesterov<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: Dim,
+    DefaultAllocator: Allocator<Scalar, Dimension>,
+{
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        x0: VectorN<Scalar, Dimension>,
+        f: Arc<dyn Fn(VectorN<Scalar, Dimension>) -> Scalar>,
+        df: Gradient<Scalar, Dimension>,
+        comparator: std::cmp::Ordering,
+        lipschitz: LipschitzEstimate<Scalar>,
+        restart: Option<Restart>,
+        eps: Scalar,
+        max_iters: usize,
+    ) -> Self {
+        let sign = if comparator == std::cmp::Ordering::Less {
+            Scalar::one()
+        } else {
+            -Scalar::one()
+        };
+        let l = match lipschitz {
+            LipschitzEstimate::Known(l) => l,
+            LipschitzEstimate::Backtracking { initial, .. } => initial,
+        };
+        // a factor that does not grow the estimate would backtrack forever
+        let error = match lipschitz {
+            LipschitzEstimate::Backtracking { factor, .. } if factor <= Scalar::one() => {
+                Some(SearchError::InvalidParameter("factor"))
+            }
+            _ => None,
+        };
+        let (fx, func_calls) = match restart {
+            Some(Restart::FunctionValue) => (Some(f(x0.clone()) * sign), 1),
+            _ => (None, 0),
+        };
+        Self {
+            dx: x0.map(|_| Scalar::max_value()),
+            g: df(x0.clone()) * sign,
+            y: x0.clone(),
+            x: x0,
+            fx,
+            t: Scalar::one(),
+            l,
+            sign,
+            f,
+            df,
+            lipschitz,
+            restart,
+            eps,
+            iters: 0,
+            error,
+            func_calls,
+            derivative_calls: 1,
+            max_iters,
+        }
+    }
+
+    // the gradient step from `y` with the value of `sign * f` there, if it was needed, and the calls spent
+    fn step(&mut self) -> Option<(VectorN<Scalar, Dimension>, Option<Scalar>, usize)> {
+        let two = Scalar::from_i8(2).unwrap();
+        match self.lipschitz {
+            LipschitzEstimate::Known(_) => {
+                let x = &self.y + self.S() / self.l;
+                match self.restart {
+                    Some(Restart::FunctionValue) => {
+                        let fx = (self.f)(x.clone()) * self.sign;
+                        Some((x, Some(fx), 1))
+                    }
+                    _ => Some((x, None, 0)),
+                }
+            }
+            // `l` grows until the sufficient decrease `f(x) <= f(y) - |g|^2 / 2l` holds
+            LipschitzEstimate::Backtracking { factor, .. } => {
+                let fy = (self.f)(self.y.clone()) * self.sign;
+                let mut func_calls = 1;
+                loop {
+                    let x = &self.y + self.S() / self.l;
+                    let fx = (self.f)(x.clone()) * self.sign;
+                    func_calls += 1;
+                    if fx <= fy - self.g.norm_squared() / (two * self.l) {
+                        return Some((x, Some(fx), func_calls));
+                    }
+                    if func_calls > self.max_iters {
+                        self.error = Some(SearchError::BacktrackingFailed);
+                        return None;
+                    }
+                    self.l *= factor;
+                }
+            }
+        }
+    }
+}
+
+impl<Scalar, Dimension> DescentSearcher<Scalar, Dimension> for Nesterov<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: Dim,
+    DefaultAllocator: Allocator<Scalar, Dimension>,
+{
+    fn S(&self) -> VectorN<Scalar, Dimension> {
+        -self.g.clone()
+    }
+}
+
+impl<Scalar, Dimension> extremum_searcher::Search<VectorN<Scalar, Dimension>>
+    for Nesterov<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: Dim,
+    DefaultAllocator: Allocator<Scalar, Dimension>,
+{
+    fn iters(&self) -> usize {
+        self.iters
+    }
+    fn error(&self) -> Option<SearchError> {
+        self.error
+    }
+    fn func_calls(&self) -> usize {
+        self.func_calls
+    }
+    fn derivative_calls(&self) -> usize {
+        self.derivative_calls
+    }
+    fn x(&self) -> VectorN<Scalar, Dimension> {
+        self.x.clone()
+    }
+    fn dx(&self) -> VectorN<Scalar, Dimension> {
+        self.dx.clone()
+    }
+}
+
+impl<Scalar, Dimension> Iterator for Nesterov<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: Dim,
+    DefaultAllocator: Allocator<Scalar, Dimension>,
+{
+    type Item = IterationResult<VectorN<Scalar, Dimension>>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.error.is_some() {
+            return None;
+        }
+        let is_extra = self.g.norm() < self.eps
+            || self.dx.iter().all(|xi| xi.abs() < self.eps)
+            || self.iters >= self.max_iters;
+        let (x, fx, func_calls) = self.step()?;
+        self.dx = &x - &self.x;
+        let restart = match self.restart {
+            Some(Restart::FunctionValue) => match (fx, self.fx) {
+                (Some(fx), Some(_fx)) => fx > _fx,
+                _ => false,
+            },
+            Some(Restart::Gradient) => self.g.dot(&self.dx) > Scalar::zero(),
+            None => false,
+        };
+        if restart {
+            self.t = Scalar::one();
+            self.y = x.clone();
+        } else {
+            let (two, four) = (Scalar::from_i8(2).unwrap(), Scalar::from_i8(4).unwrap());
+            let t = (Scalar::one() + (Scalar::one() + four * self.t * self.t).sqrt()) / two;
+            self.y = &x + &self.dx * ((self.t - Scalar::one()) / t);
+            self.t = t;
+        }
+        self.x = x;
+        self.fx = fx;
+        self.iters += 1;
+        self.g = (self.df)(self.y.clone()) * self.sign;
+
+        self.func_calls += func_calls;
+        self.derivative_calls += 1;
+        Some(
+            IterationResult::new(self.x.clone(), self.dx.clone(), func_calls, is_extra)
+                .with_derivative_calls(1),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::searchers::descent_searchers::search::{Method, Search};
+    use nalgebra::{Vector2, U2};
+
+    type Function = Arc<dyn Fn(Vector2<f64>) -> f64>;
+
+    // the gradient is lipschitz with `L = 20`
+    fn quadratic() -> (Function, Gradient<f64, U2>) {
+        (
+            Arc::new(|x| 10.0 * (x[0] - 1.0).powi(2) + 0.5 * (x[1] + 2.0).powi(2)),
+            Arc::new(|x| Vector2::new(20.0 * (x[0] - 1.0), x[1] + 2.0)),
+        )
+    }
+
+    fn minimum(lipschitz: LipschitzEstimate<f64>, restart: Option<Restart>) -> Vector2<f64> {
+        let (f, df) = quadratic();
+        Search::Mnimimum(
+            Vector2::new(-3.0, 4.0),
+            f,
+            Method::Nesterov {
+                df,
+                lipschitz,
+                restart,
+            },
+            1e-10,
+            10000,
+        )
+        .unwrap()
+        .x()
+    }
+
+    #[test]
+    fn every_estimate_and_restart_minimises_a_quadratic() {
+        for restart in [None, Some(Restart::FunctionValue), Some(Restart::Gradient)] {
+            for lipschitz in [
+                LipschitzEstimate::Known(20.0),
+                LipschitzEstimate::Backtracking {
+                    initial: 1.0,
+                    factor: 2.0,
+                },
+            ] {
+                let x = minimum(lipschitz, restart.clone());
+                assert!((x - Vector2::new(1.0, -2.0)).norm() < 1e-6);
+            }
+        }
+    }
+
+    #[test]
+    fn backtracking_factor_must_grow_the_estimate() {
+        let (f, df) = quadratic();
+        let result = Search::Mnimimum(
+            Vector2::new(-3.0, 4.0),
+            f,
+            Method::Nesterov {
+                df,
+                lipschitz: LipschitzEstimate::Backtracking {
+                    initial: 1.0,
+                    factor: 1.0,
+                },
+                restart: None,
+            },
+            1e-10,
+            100,
+        );
+        assert_eq!(result.err(), Some(SearchError::InvalidParameter("factor")));
+    }
+
+    #[test]
+    fn exhausted_backtracking_is_reported() {
+        // an uphill gradient never gives a sufficient decrease
+        let (f, _) = quadratic();
+        let result = Search::Mnimimum(
+            Vector2::new(-3.0, 4.0),
+            f,
+            Method::Nesterov {
+                df: Arc::new(|x| Vector2::new(-20.0 * (x[0] - 1.0), -x[1] - 2.0)),
+                lipschitz: LipschitzEstimate::Backtracking {
+                    initial: 1.0,
+                    factor: 2.0,
+                },
+                restart: None,
+            },
+            1e-10,
+            20,
+        );
+        assert_eq!(result.err(), Some(SearchError::BacktrackingFailed));
+    }
+}
@@ -0,0 +1,352 @@
+use super::search::{DescentSearcher, Gradient, Hessian, Modification};
+use crate::searchers::extremum_searcher;
+use crate::searchers::extremum_searcher::{IterationResult, SearchError};
+use crate::searchers::line_searchers;
+use nalgebra::{
+    allocator::Allocator, DMatrix, DVector, DefaultAllocator, DimSub, Dynamic, MatrixN, RealField,
+    VectorN, U1,
+};
+use std::sync::Arc;
+
+pub struct Newton<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: DimSub<Dynamic>,
+    DefaultAllocator: Allocator<Scalar, Dimension> + Allocator<Scalar, Dimension, Dimension>,
+{
+    x: VectorN<Scalar, Dimension>,
+    dx: VectorN<Scalar, Dimension>,
+    g: VectorN<Scalar, Dimension>,
+    s: VectorN<Scalar, Dimension>,
+    sign: Scalar,
+    func_calls: usize,
+    derivative_calls: usize,
+    hessian_calls: usize,
+    factorizations: usize,
+    iters: usize,
+    error: Option<SearchError>,
+    f: Arc<dyn Fn(VectorN<Scalar, Dimension>) -> Scalar>,
+    df: Gradient<Scalar, Dimension>,
+    ddf: Hessian<Scalar, Dimension>,
+    eps: Scalar,
+    max_iters: usize,
+    comparator: std::cmp::Ordering,
+    line_search: Option<line_searchers::search::Method<Scalar>>,
+    modification: Option<Modification<Scalar>>,
+}
+
+impl<Scalar, Dimension> Newton<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: DimSub<Dynamic>,
+    DefaultAllocator: Allocator<Scalar, Dimension> + Allocator<Scalar, Dimension, Dimension>,
+{
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        x0: VectorN<Scalar, Dimension>,
+        f: Arc<dyn Fn(VectorN<Scalar, Dimension>) -> Scalar>,
+        df: Gradient<Scalar, Dimension>,
+        ddf: Hessian<Scalar, Dimension>,
+        comparator: std::cmp::Ordering,
+        line_search: Option<line_searchers::search::Method<Scalar>>,
+        modification: Option<Modification<Scalar>>,
+        eps: Scalar,
+        max_iters: usize,
+    ) -> Self {
+        // works with `sign * f`, so the modifications push the hessian towards positive definite
+        let sign = if comparator == std::cmp::Ordering::Less {
+            Scalar::one()
+        } else {
+            -Scalar::one()
+        };
+        let g = df(x0.clone()) * sign;
+        Self {
+            dx: x0.map(|_| Scalar::max_value()),
+            x: x0,
+            s: -g.clone(),
+            g,
+            sign,
+            f,
+            df,
+            ddf,
+            eps,
+            iters: 0,
+            error: None,
+            func_calls: 0,
+            derivative_calls: 1,
+            hessian_calls: 0,
+            factorizations: 0,
+            max_iters,
+            comparator,
+            line_search,
+            modification,
+        }
+    }
+
+    // solves `h s = -g`, returns `None` if `h` is singular, along with the factorisations it took
+    fn direction(
+        &self,
+        h: MatrixN<Scalar, Dimension>,
+    ) -> (Option<VectorN<Scalar, Dimension>>, usize) {
+        match &self.modification {
+            None => (h.try_inverse().map(|inverse| -(inverse * &self.g)), 1),
+            Some(Modification::Eigenvalue { delta }) => {
+                // decomposed as a dynamic matrix, so the decomposition puts no bounds on `Dimension`
+                let n = self.x.len();
+                let eigen = DMatrix::from_iterator(n, n, h.iter().cloned()).symmetric_eigen();
+                let g = DVector::from_iterator(n, self.g.iter().cloned());
+                let inverse = eigen
+                    .eigenvalues
+                    .map(|lambda| Scalar::one() / lambda.max(*delta));
+                let s =
+                    &eigen.eigenvectors * (eigen.eigenvectors.tr_mul(&g)).component_mul(&inverse);
+                let s = VectorN::<Scalar, Dimension>::from_iterator_generic(
+                    Dimension::from_usize(n),
+                    U1,
+                    s.iter().map(|si| -*si),
+                );
+                (Some(s), 1)
+            }
+            Some(Modification::CholeskyShift { beta }) => {
+                let n = Dimension::from_usize(self.x.len());
+                let identity = MatrixN::<Scalar, Dimension>::identity_generic(n, n);
+                let min_diagonal = h.diagonal().min();
+                let mut tau = if min_diagonal > Scalar::zero() {
+                    Scalar::zero()
+                } else {
+                    *beta - min_diagonal
+                };
+                let mut factorizations = 0;
+                loop {
+                    factorizations += 1;
+                    match (h.clone() + &identity * tau).cholesky() {
+                        Some(cholesky) => return (Some(-cholesky.solve(&self.g)), factorizations),
+                        None if factorizations >= self.max_iters => return (None, factorizations),
+                        None => {
+                            tau = (tau * Scalar::from_i8(2).unwrap()).max(*beta);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<Scalar, Dimension> DescentSearcher<Scalar, Dimension> for Newton<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: DimSub<Dynamic>,
+    DefaultAllocator: Allocator<Scalar, Dimension> + Allocator<Scalar, Dimension, Dimension>,
+{
+    fn S(&self) -> VectorN<Scalar, Dimension> {
+        self.s.clone()
+    }
+}
+
+impl<Scalar, Dimension> extremum_searcher::Search<VectorN<Scalar, Dimension>>
+    for Newton<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: DimSub<Dynamic>,
+    DefaultAllocator: Allocator<Scalar, Dimension> + Allocator<Scalar, Dimension, Dimension>,
+{
+    fn iters(&self) -> usize {
+        self.iters
+    }
+    fn error(&self) -> Option<SearchError> {
+        self.error
+    }
+    fn func_calls(&self) -> usize {
+        self.func_calls
+    }
+    fn derivative_calls(&self) -> usize {
+        self.derivative_calls
+    }
+    fn hessian_calls(&self) -> usize {
+        self.hessian_calls
+    }
+    fn factorizations(&self) -> usize {
+        self.factorizations
+    }
+    fn x(&self) -> VectorN<Scalar, Dimension> {
+        self.x.clone()
+    }
+    fn dx(&self) -> VectorN<Scalar, Dimension> {
+        self.dx.clone()
+    }
+}
+
+impl<Scalar, Dimension> Iterator for Newton<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: DimSub<Dynamic>,
+    DefaultAllocator: Allocator<Scalar, Dimension> + Allocator<Scalar, Dimension, Dimension>,
+{
+    type Item = IterationResult<VectorN<Scalar, Dimension>>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let is_extra = self.g.norm() < self.eps
+            || self.dx.iter().all(|xi| xi.abs() < self.eps)
+            || self.iters >= self.max_iters;
+        // nothing is searched once the search is over, the direction may no longer descend
+        if is_extra {
+            return Some(IterationResult::new(
+                self.x.clone(),
+                self.x.map(|_| Scalar::zero()),
+                0,
+                true,
+            ));
+        }
+        let h = (self.ddf)(self.x.clone()) * self.sign;
+        self.hessian_calls += 1;
+        let (s, factorizations) = self.direction(h);
+        self.factorizations += factorizations;
+        self.s = match s {
+            Some(s) => s,
+            None => {
+                self.error = Some(SearchError::SingularHessian);
+                return None;
+            }
+        };
+
+        let (lambda, func_calls, derivative_calls) = match &self.line_search {
+            Some(line_search) => {
+                let x = self.x.clone();
+                let f = self.f.clone();
+                let df = self.df.clone();
+                let s = self.S();
+                let (_x, _s) = (x.clone(), s.clone());
+                match line_searchers::search::Search::result(
+                    Arc::new(move |lambda| f(x.clone() + s.clone() * lambda)),
+                    Some(Arc::new(move |lambda| {
+                        df(_x.clone() + _s.clone() * lambda).dot(&_s)
+                    })),
+                    self.comparator,
+                    line_search.clone(),
+                    Scalar::one(),
+                    self.eps,
+                    self.max_iters,
+                ) {
+                    Ok(result) => (result.x(), result.func_calls(), result.derivative_calls()),
+                    Err(error) => {
+                        self.error = Some(error);
+                        return None;
+                    }
+                }
+            }
+            None => (Scalar::one(), 0, 0),
+        };
+        self.dx = self.S() * lambda;
+        self.x += self.dx.clone();
+        self.iters += 1;
+        self.g = (self.df)(self.x.clone()) * self.sign;
+
+        self.func_calls += func_calls;
+        self.derivative_calls += derivative_calls + 1;
+        Some(
+            IterationResult::new(self.x.clone(), self.dx.clone(), func_calls, is_extra)
+                .with_derivative_calls(derivative_calls + 1)
+                .with_hessian_calls(1)
+                .with_factorizations(factorizations),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::searchers::descent_searchers::search::{Method, Search};
+    use nalgebra::{Matrix2, Vector2, U2};
+
+    type Function = Arc<dyn Fn(Vector2<f64>) -> f64>;
+
+    fn quadratic() -> (Function, Gradient<f64, U2>, Hessian<f64, U2>) {
+        (
+            Arc::new(|x| 3.0 * (x[0] - 1.0).powi(2) + (x[1] + 2.0).powi(2) + x[0] * x[1]),
+            Arc::new(|x| Vector2::new(6.0 * (x[0] - 1.0) + x[1], 2.0 * (x[1] + 2.0) + x[0])),
+            Arc::new(|_| Matrix2::new(6.0, 1.0, 1.0, 2.0)),
+        )
+    }
+
+    #[test]
+    fn full_step_solves_a_quadratic_in_one_iteration() {
+        let (f, df, ddf) = quadratic();
+        let mut newton = Newton::new(
+            Vector2::new(5.0, 5.0),
+            f,
+            df.clone(),
+            ddf,
+            std::cmp::Ordering::Less,
+            None,
+            None,
+            1e-8,
+            100,
+        );
+        let x = newton.next().unwrap().x();
+        assert!(df(x).norm() < 1e-10);
+    }
+
+    #[test]
+    fn eigenvalue_modification_descends_on_a_saddle() {
+        let f: Function = Arc::new(|x| x[0].powi(2) - x[1].powi(2) + x[1].powi(4));
+        let df: Gradient<f64, U2> =
+            Arc::new(|x| Vector2::new(2.0 * x[0], -2.0 * x[1] + 4.0 * x[1].powi(3)));
+        let ddf: Hessian<f64, U2> =
+            Arc::new(|x| Matrix2::new(2.0, 0.0, 0.0, -2.0 + 12.0 * x[1].powi(2)));
+        let result = Search::Mnimimum(
+            Vector2::new(1.0, 0.1),
+            f,
+            Method::Newton {
+                df,
+                ddf,
+                line_search: Some(line_searchers::search::Method::Armijo { c1: 1e-4, rho: 0.5 }),
+                modification: Some(Modification::Eigenvalue { delta: 1e-2 }),
+            },
+            1e-8,
+            100,
+        )
+        .unwrap();
+        assert!((result.x() - Vector2::new(0.0, 0.5f64.sqrt())).norm() < 1e-6);
+    }
+
+    #[test]
+    fn cholesky_shift_matches_the_minimum() {
+        let (f, df, ddf) = quadratic();
+        let result = Search::Mnimimum(
+            Vector2::new(5.0, 5.0),
+            f,
+            Method::Newton {
+                df: df.clone(),
+                ddf,
+                line_search: None,
+                modification: Some(Modification::CholeskyShift { beta: 1e-3 }),
+            },
+            1e-8,
+            100,
+        )
+        .unwrap();
+        assert!(df(result.x()).norm() < 1e-8);
+        // one hessian per iteration, and at least one factorisation of it
+        assert_eq!(result.hessian_calls(), result.iters());
+        assert!(result.factorizations() >= result.hessian_calls());
+    }
+
+    #[test]
+    fn singular_hessian_is_reported() {
+        let f: Function = Arc::new(|x| x[0].powi(2));
+        let df: Gradient<f64, U2> = Arc::new(|x| Vector2::new(2.0 * x[0], 0.0));
+        let ddf: Hessian<f64, U2> = Arc::new(|_| Matrix2::new(2.0, 0.0, 0.0, 0.0));
+        let result = Search::Mnimimum(
+            Vector2::new(1.0, 1.0),
+            f,
+            Method::Newton {
+                df,
+                ddf,
+                line_search: None,
+                modification: None,
+            },
+            1e-8,
+            100,
+        );
+        assert_eq!(result.err(), Some(SearchError::SingularHessian));
+    }
+}
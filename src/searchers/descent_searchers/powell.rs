@@ -0,0 +1,286 @@
+use super::search::DescentSearcher;
+use crate::searchers::extremum_searcher;
+use crate::searchers::extremum_searcher::{IterationResult, SearchError};
+use crate::searchers::one_dimension_searchers;
+use crate::searchers::one_dimension_searchers::search::Bracketing;
+use nalgebra::{allocator::Allocator, DefaultAllocator, Dim, RealField, VectorN};
+use std::sync::Arc;
+
+pub struct Powell<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: Dim,
+    DefaultAllocator: Allocator<Scalar, Dimension>,
+{
+    x: VectorN<Scalar, Dimension>,
+    dx: VectorN<Scalar, Dimension>,
+    // value of `sign * f` at `x`
+    fx: Scalar,
+    directions: Vec<VectorN<Scalar, Dimension>>,
+    sign: Scalar,
+    func_calls: usize,
+    iters: usize,
+    error: Option<SearchError>,
+    f: Arc<dyn Fn(VectorN<Scalar, Dimension>) -> Scalar>,
+    method: one_dimension_searchers::search::Method<Scalar>,
+    bracketing: Bracketing<Scalar>,
+    eps: Scalar,
+    max_iters: usize,
+    comparator: std::cmp::Ordering,
+}
+
+impl<Scalar, Dimension> Powell<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: Dim,
+    DefaultAllocator: Allocator<Scalar, Dimension>,
+{
+    pub fn new(
+        x0: VectorN<Scalar, Dimension>,
+        f: Arc<dyn Fn(VectorN<Scalar, Dimension>) -> Scalar>,
+        comparator: std::cmp::Ordering,
+        method: one_dimension_searchers::search::Method<Scalar>,
+        bracketing: Bracketing<Scalar>,
+        eps: Scalar,
+        max_iters: usize,
+    ) -> Self {
+        let sign = if comparator == std::cmp::Ordering::Less {
+            Scalar::one()
+        } else {
+            -Scalar::one()
+        };
+        let directions = (0..x0.len())
+            .map(|i| {
+                let mut d = x0.map(|_| Scalar::zero());
+                d[i] = Scalar::one();
+                d
+            })
+            .collect();
+        Self {
+            fx: f(x0.clone()) * sign,
+            dx: x0.map(|_| Scalar::max_value()),
+            x: x0,
+            directions,
+            sign,
+            f,
+            method,
+            bracketing,
+            eps,
+            iters: 0,
+            error: None,
+            func_calls: 1,
+            max_iters,
+            comparator,
+        }
+    }
+
+    // returns the minimum along `s` with the value of `sign * f` there and the calls spent
+    fn line_search(
+        &self,
+        x: &VectorN<Scalar, Dimension>,
+        s: &VectorN<Scalar, Dimension>,
+    ) -> Result<(VectorN<Scalar, Dimension>, Scalar, usize), SearchError> {
+        use crate::searchers::extremum_searcher::Search;
+        let (_x, _s) = (x.clone(), s.clone());
+        let f = self.f.clone();
+        let lambda = one_dimension_searchers::search::Search::with_bracketing(
+            Scalar::zero(),
+            Arc::new(move |lambda| f(_x.clone() + _s.clone() * lambda)),
+            self.comparator,
+            self.method.clone(),
+            self.bracketing.clone(),
+            self.eps,
+            self.max_iters,
+        )?
+        .result();
+        let x = x + s * lambda.x();
+        let fx = (self.f)(x.clone()) * self.sign;
+        Ok((x, fx, lambda.func_calls() + 1))
+    }
+}
+
+impl<Scalar, Dimension> DescentSearcher<Scalar, Dimension> for Powell<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: Dim,
+    DefaultAllocator: Allocator<Scalar, Dimension>,
+{
+    // the most recently added direction
+    fn S(&self) -> VectorN<Scalar, Dimension> {
+        self.directions[self.directions.len() - 1].clone()
+    }
+}
+
+impl<Scalar, Dimension> extremum_searcher::Search<VectorN<Scalar, Dimension>>
+    for Powell<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: Dim,
+    DefaultAllocator: Allocator<Scalar, Dimension>,
+{
+    fn iters(&self) -> usize {
+        self.iters
+    }
+    fn error(&self) -> Option<SearchError> {
+        self.error
+    }
+    fn func_calls(&self) -> usize {
+        self.func_calls
+    }
+    fn x(&self) -> VectorN<Scalar, Dimension> {
+        self.x.clone()
+    }
+    fn dx(&self) -> VectorN<Scalar, Dimension> {
+        self.dx.clone()
+    }
+}
+
+impl<Scalar, Dimension> Iterator for Powell<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: Dim,
+    DefaultAllocator: Allocator<Scalar, Dimension>,
+{
+    type Item = IterationResult<VectorN<Scalar, Dimension>>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let is_extra = self.dx.iter().all(|xi| xi.abs() < self.eps) || self.iters >= self.max_iters;
+        let (x0, f0) = (self.x.clone(), self.fx);
+        let mut func_calls = 0;
+
+        // one line search along every direction, remembering the one with the largest decrease
+        let (mut x, mut fx) = (x0.clone(), f0);
+        let (mut largest, mut decrease) = (0, Scalar::zero());
+        for i in 0..self.directions.len() {
+            let (_x, _fx, calls) = match self.line_search(&x, &self.directions[i]) {
+                Ok(result) => result,
+                Err(error) => {
+                    self.error = Some(error);
+                    return None;
+                }
+            };
+            func_calls += calls;
+            if fx - _fx > decrease {
+                largest = i;
+                decrease = fx - _fx;
+            }
+            x = _x;
+            fx = _fx;
+        }
+
+        // the net displacement replaces the direction of the largest decrease, unless that
+        // would make the directions nearly linearly dependent
+        let s = &x - &x0;
+        let extrapolated = &x + &s;
+        let f_extrapolated = (self.f)(extrapolated) * self.sign;
+        func_calls += 1;
+        if f_extrapolated < f0 {
+            let two = Scalar::from_i8(2).unwrap();
+            let t = two * (f0 - two * fx + f_extrapolated) * (f0 - fx - decrease).powi(2)
+                - decrease * (f0 - f_extrapolated).powi(2);
+            if t < Scalar::zero() {
+                let (_x, _fx, calls) = match self.line_search(&x, &s) {
+                    Ok(result) => result,
+                    Err(error) => {
+                        self.error = Some(error);
+                        return None;
+                    }
+                };
+                func_calls += calls;
+                x = _x;
+                fx = _fx;
+                let last = self.directions.len() - 1;
+                self.directions[largest] = self.directions[last].clone();
+                self.directions[last] = s;
+            }
+        }
+
+        self.dx = &x - &x0;
+        self.x = x;
+        self.fx = fx;
+        self.iters += 1;
+        self.func_calls += func_calls;
+        Some(IterationResult::new(
+            self.x.clone(),
+            self.dx.clone(),
+            func_calls,
+            is_extra,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::searchers::descent_searchers::search::{Method, Search};
+    use crate::searchers::extremum_searcher::Search as _;
+    use nalgebra::{Vector2, U2};
+
+    fn powell(
+        f: Arc<dyn Fn(Vector2<f64>) -> f64>,
+        comparator: std::cmp::Ordering,
+    ) -> Powell<f64, U2> {
+        Powell::new(
+            Vector2::new(-3.0, 4.0),
+            f,
+            comparator,
+            one_dimension_searchers::search::Method::Brent,
+            Bracketing::default(),
+            1e-10,
+            1000,
+        )
+    }
+
+    // the hessian is [[8, 3], [3, 2]], the minimum is at (2, -5)
+    fn quadratic(x: Vector2<f64>) -> f64 {
+        4.0 * (x[0] - 2.0).powi(2) + (x[1] + 5.0).powi(2) + 3.0 * (x[0] - 2.0) * (x[1] + 5.0)
+    }
+
+    #[test]
+    fn starts_from_the_coordinate_directions() {
+        let searcher = powell(Arc::new(quadratic), std::cmp::Ordering::Less);
+        assert_eq!(
+            searcher.directions,
+            vec![Vector2::new(1.0, 0.0), Vector2::new(0.0, 1.0)]
+        );
+    }
+
+    #[test]
+    fn finishes_a_quadratic_in_n_cycles() {
+        let mut searcher = powell(Arc::new(quadratic), std::cmp::Ordering::Less);
+        searcher.next().unwrap();
+        let r = searcher.next().unwrap();
+        assert!((r.x() - Vector2::new(2.0, -5.0)).norm() < 1e-6);
+    }
+
+    #[test]
+    fn replaces_a_direction_with_the_net_displacement() {
+        let mut searcher = powell(Arc::new(quadratic), std::cmp::Ordering::Less);
+        let r = searcher.next().unwrap();
+        let s = searcher.S();
+        // the displacement is searched along once more after the cycle, so it is parallel to `dx`
+        assert!((s[0] * r.dx()[1] - s[1] * r.dx()[0]).abs() < 1e-6 * r.dx().norm());
+        assert_ne!(s, Vector2::new(0.0, 1.0));
+    }
+
+    #[test]
+    fn minimises_the_rosenbrock_function() {
+        let result = Search::Mnimimum(
+            Vector2::new(-1.2, 1.0),
+            Arc::new(|x: Vector2<f64>| 100.0 * (x[1] - x[0] * x[0]).powi(2) + (1.0 - x[0]).powi(2)),
+            Method::Powell {
+                method: one_dimension_searchers::search::Method::Brent,
+                bracketing: Bracketing::default(),
+            },
+            1e-10,
+            1000,
+        )
+        .unwrap();
+        assert!((result.x() - Vector2::new(1.0, 1.0)).norm() < 1e-4);
+    }
+
+    #[test]
+    fn maximises_with_the_greater_comparator() {
+        let result = powell(Arc::new(|x| -quadratic(x)), std::cmp::Ordering::Greater).result();
+        assert!((result.x() - Vector2::new(2.0, -5.0)).norm() < 1e-6);
+    }
+}
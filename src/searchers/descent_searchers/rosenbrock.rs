@@ -0,0 +1,344 @@
+use super::search::{DescentSearcher, Exploration};
+use crate::searchers::extremum_searcher;
+use crate::searchers::extremum_searcher::{IterationResult, SearchError};
+use crate::searchers::line_searchers;
+use nalgebra::{allocator::Allocator, DefaultAllocator, Dim, RealField, VectorN};
+use std::sync::Arc;
+
+pub struct Rosenbrock<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: Dim,
+    DefaultAllocator: Allocator<Scalar, Dimension>,
+{
+    x: VectorN<Scalar, Dimension>,
+    dx: VectorN<Scalar, Dimension>,
+    // only kept up to date by the discrete variant
+    fx: Scalar,
+    // orthonormal search directions, the current one is `directions[k]`
+    directions: Vec<VectorN<Scalar, Dimension>>,
+    k: usize,
+    // total move along every direction during the current stage
+    lambdas: Vec<Scalar>,
+    // trial steps and whether a success and a failure were seen along every direction, discrete variant only
+    steps: Vec<Scalar>,
+    outcomes: Vec<(bool, bool)>,
+    // displacement over the last completed stage
+    stage: Scalar,
+    func_calls: usize,
+    iters: usize,
+    error: Option<SearchError>,
+    f: Arc<dyn Fn(VectorN<Scalar, Dimension>) -> Scalar>,
+    exploration: Exploration<Scalar>,
+    eps: Scalar,
+    max_iters: usize,
+    comparator: std::cmp::Ordering,
+}
+
+impl<Scalar, Dimension> Rosenbrock<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: Dim,
+    DefaultAllocator: Allocator<Scalar, Dimension>,
+{
+    pub fn new(
+        x0: VectorN<Scalar, Dimension>,
+        f: Arc<dyn Fn(VectorN<Scalar, Dimension>) -> Scalar>,
+        comparator: std::cmp::Ordering,
+        exploration: Exploration<Scalar>,
+        eps: Scalar,
+        max_iters: usize,
+    ) -> Self {
+        let n = x0.len();
+        let directions = (0..n)
+            .map(|i| {
+                let mut d = x0.map(|_| Scalar::zero());
+                d[i] = Scalar::one();
+                d
+            })
+            .collect();
+        let (step, fx, func_calls) = match &exploration {
+            Exploration::Discrete { step, .. } => (*step, f(x0.clone()), 1),
+            Exploration::LineSearch { .. } => (Scalar::zero(), Scalar::zero(), 0),
+        };
+        Self {
+            fx,
+            dx: x0.map(|_| Scalar::max_value()),
+            x: x0,
+            directions,
+            k: 0,
+            lambdas: vec![Scalar::zero(); n],
+            steps: vec![step; n],
+            outcomes: vec![(false, false); n],
+            stage: Scalar::max_value(),
+            f,
+            exploration,
+            eps,
+            iters: 0,
+            error: None,
+            func_calls,
+            max_iters,
+            comparator,
+        }
+    }
+
+    fn is_better(&self, f1: Scalar, f2: Scalar) -> bool {
+        f1.partial_cmp(&f2) == Some(self.comparator)
+    }
+
+    // Gram-Schmidt over the partial sums of the moves, largest moves first
+    fn rotate(&mut self) {
+        let n = self.directions.len();
+        let mut order: Vec<usize> = (0..n).collect();
+        // a NaN move is ordered last
+        let magnitude = |lambda: Scalar| {
+            if lambda.partial_cmp(&lambda).is_some() {
+                lambda.abs()
+            } else {
+                -Scalar::one()
+            }
+        };
+        order.sort_by(|&i, &j| {
+            magnitude(self.lambdas[j])
+                .partial_cmp(&magnitude(self.lambdas[i]))
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        let directions: Vec<_> = order.iter().map(|&i| self.directions[i].clone()).collect();
+        let lambdas: Vec<_> = order.iter().map(|&i| self.lambdas[i]).collect();
+        let displacement = directions
+            .iter()
+            .zip(lambdas.iter())
+            .fold(self.x.map(|_| Scalar::zero()), |a, (d, lambda)| {
+                a + d * *lambda
+            });
+        self.stage = displacement.norm();
+
+        let mut a = displacement;
+        let mut rotated: Vec<VectorN<Scalar, Dimension>> = Vec::with_capacity(n);
+        for i in 0..n {
+            let orthogonalize = |v: &VectorN<Scalar, Dimension>| {
+                rotated
+                    .iter()
+                    .fold(v.clone(), |b, d: &VectorN<Scalar, Dimension>| {
+                        b - d * d.dot(v)
+                    })
+            };
+            let b = orthogonalize(&a);
+            // directions without progress keep their old orientation, the moves left in `a` are
+            // along the old directions from `i` on, which are orthogonal to the rotated ones
+            if b.norm() < self.eps {
+                rotated.push(directions[i].clone());
+            } else {
+                rotated.push(b.normalize());
+            }
+            a -= &directions[i] * lambdas[i];
+        }
+        self.directions = rotated;
+        self.lambdas = vec![Scalar::zero(); n];
+        self.outcomes = vec![(false, false); n];
+        if let Exploration::Discrete { step, .. } = &self.exploration {
+            self.steps = vec![*step; n];
+        }
+    }
+}
+
+impl<Scalar, Dimension> DescentSearcher<Scalar, Dimension> for Rosenbrock<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: Dim,
+    DefaultAllocator: Allocator<Scalar, Dimension>,
+{
+    fn S(&self) -> VectorN<Scalar, Dimension> {
+        self.directions[self.k].clone()
+    }
+}
+
+impl<Scalar, Dimension> extremum_searcher::Search<VectorN<Scalar, Dimension>>
+    for Rosenbrock<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: Dim,
+    DefaultAllocator: Allocator<Scalar, Dimension>,
+{
+    fn iters(&self) -> usize {
+        self.iters
+    }
+    fn error(&self) -> Option<SearchError> {
+        self.error
+    }
+    fn func_calls(&self) -> usize {
+        self.func_calls
+    }
+    fn x(&self) -> VectorN<Scalar, Dimension> {
+        self.x.clone()
+    }
+    fn dx(&self) -> VectorN<Scalar, Dimension> {
+        self.dx.clone()
+    }
+}
+
+impl<Scalar, Dimension> Iterator for Rosenbrock<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: Dim,
+    DefaultAllocator: Allocator<Scalar, Dimension>,
+{
+    type Item = IterationResult<VectorN<Scalar, Dimension>>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let steps_exhausted = match self.exploration {
+            Exploration::Discrete { .. } => self.steps.iter().all(|h| h.abs() < self.eps),
+            Exploration::LineSearch { .. } => false,
+        };
+        let is_extra = self.stage < self.eps || steps_exhausted || self.iters >= self.max_iters;
+        let s = self.S();
+        let (lambda, func_calls) = match &self.exploration {
+            Exploration::Discrete {
+                expansion,
+                contraction,
+                ..
+            } => {
+                let h = self.steps[self.k];
+                let _x = self.x.clone() + &s * h;
+                let _fx = (self.f)(_x);
+                if self.is_better(_fx, self.fx) {
+                    self.fx = _fx;
+                    self.steps[self.k] = h * *expansion;
+                    self.outcomes[self.k].0 = true;
+                    (h, 1)
+                } else {
+                    self.steps[self.k] = -h * *contraction;
+                    self.outcomes[self.k].1 = true;
+                    (Scalar::zero(), 1)
+                }
+            }
+            Exploration::LineSearch { line_search } => {
+                let x = self.x.clone();
+                let f = self.f.clone();
+                let _s = s.clone();
+                match line_searchers::search::Search::either_way(
+                    Arc::new(move |lambda| f(x.clone() + _s.clone() * lambda)),
+                    None,
+                    self.comparator,
+                    line_search.clone(),
+                    Scalar::one(),
+                    self.eps,
+                    self.max_iters,
+                ) {
+                    Ok(result) => (result.x(), result.func_calls()),
+                    Err(error) => {
+                        self.error = Some(error);
+                        return None;
+                    }
+                }
+            }
+        };
+        self.dx = s * lambda;
+        self.x += self.dx.clone();
+        self.lambdas[self.k] += lambda;
+        self.k = (self.k + 1) % self.directions.len();
+        let stage_completed = match self.exploration {
+            Exploration::Discrete { .. } => self.outcomes.iter().all(|&(s, f)| s && f),
+            Exploration::LineSearch { .. } => self.k == 0,
+        };
+        if stage_completed {
+            self.rotate();
+            self.k = 0;
+        }
+        self.iters += 1;
+        self.func_calls += func_calls;
+        Some(IterationResult::new(
+            self.x.clone(),
+            self.dx.clone(),
+            func_calls,
+            is_extra,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::searchers::descent_searchers::search::{Method, Search};
+    use crate::searchers::one_dimension_searchers;
+    use crate::searchers::one_dimension_searchers::search::Bracketing;
+    use nalgebra::{Vector2, Vector3, U2};
+
+    fn rosenbrock() -> Arc<dyn Fn(Vector2<f64>) -> f64> {
+        Arc::new(|x| 100.0 * (x[1] - x[0] * x[0]).powi(2) + (1.0 - x[0]).powi(2))
+    }
+
+    fn explorations() -> Vec<Exploration<f64>> {
+        vec![
+            Exploration::Discrete {
+                step: 0.1,
+                expansion: 3.0,
+                contraction: 0.5,
+            },
+            Exploration::LineSearch {
+                line_search: line_searchers::search::Method::Exact {
+                    method: one_dimension_searchers::search::Method::Brent,
+                    bracketing: Bracketing::default(),
+                },
+            },
+        ]
+    }
+
+    #[test]
+    fn minimises_the_rosenbrock_function() {
+        for exploration in explorations() {
+            let result = Search::<f64, U2>::Mnimimum(
+                Vector2::new(-1.2, 1.0),
+                rosenbrock(),
+                Method::Rosenbrock { exploration },
+                1e-9,
+                10000,
+            )
+            .unwrap();
+            assert!((result.x() - Vector2::new(1.0, 1.0)).norm() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn directions_without_progress_are_kept() {
+        let mut searcher = Rosenbrock::new(
+            Vector3::new(0.0, 0.0, 0.0),
+            Arc::new(|x: Vector3<f64>| x.norm_squared()),
+            std::cmp::Ordering::Less,
+            explorations().remove(1),
+            1e-3,
+            100,
+        );
+        // the move along `d2` is below `eps`, so `d2` is not turned round to follow it
+        let (d1, d2) = (Vector3::new(0.6, 0.8, 0.0), Vector3::new(-0.8, 0.6, 0.0));
+        searcher.directions = vec![d1, d2, Vector3::new(0.0, 0.0, 1.0)];
+        searcher.lambdas = vec![2.0, -1e-5, 0.0];
+        searcher.rotate();
+        assert!((searcher.directions[0] - d1).norm() < 1e-5);
+        assert_eq!(searcher.directions[1], d2);
+        assert_eq!(searcher.directions[2], Vector3::new(0.0, 0.0, 1.0));
+    }
+
+    #[test]
+    fn rotated_directions_are_orthonormal() {
+        let mut searcher = Rosenbrock::new(
+            Vector3::new(0.0, 0.0, 0.0),
+            Arc::new(|x: Vector3<f64>| x.norm_squared()),
+            std::cmp::Ordering::Less,
+            explorations().remove(1),
+            1e-9,
+            100,
+        );
+        searcher.lambdas = vec![0.5, -2.0, 1e-3];
+        searcher.rotate();
+        for i in 0..3 {
+            for j in 0..3 {
+                let expected = if i == j { 1.0 } else { 0.0 };
+                let dot = searcher.directions[i].dot(&searcher.directions[j]);
+                assert!((dot - expected).abs() < 1e-12);
+            }
+        }
+        // the first direction points along the displacement of the stage
+        let displacement = Vector3::new(0.5, -2.0, 1e-3);
+        assert!((searcher.directions[0] - displacement.normalize()).norm() < 1e-12);
+    }
+}
@@ -1,34 +1,286 @@
 use crate::searchers::extremum_searcher;
-use crate::searchers::extremum_searcher::{FinalResult, IterationResult};
-use nalgebra::{allocator::Allocator, DefaultAllocator, DimName, RealField, VectorN};
+use crate::searchers::extremum_searcher::{FinalResult, IterationResult, SearchError};
+use crate::searchers::line_searchers;
+use crate::searchers::one_dimension_searchers;
+use crate::searchers::one_dimension_searchers::search::Bracketing;
+use nalgebra::{
+    allocator::Allocator, DefaultAllocator, Dim, DimSub, Dynamic, MatrixN, RealField, VectorN,
+};
+use std::num::NonZeroUsize;
 use std::sync::Arc;
 
+pub type Gradient<Scalar, Dimension> =
+    Arc<dyn Fn(VectorN<Scalar, Dimension>) -> VectorN<Scalar, Dimension>>;
+pub type Hessian<Scalar, Dimension> =
+    Arc<dyn Fn(VectorN<Scalar, Dimension>) -> MatrixN<Scalar, Dimension>>;
+
 pub trait DescentSearcher<Scalar, Dimension>:
     Iterator<Item = IterationResult<VectorN<Scalar, Dimension>>>
     + extremum_searcher::Search<VectorN<Scalar, Dimension>>
 where
     Scalar: RealField,
-    Dimension: DimName,
+    Dimension: Dim,
     DefaultAllocator: Allocator<Scalar, Dimension>,
 {
     #[allow(non_snake_case)]
     fn S(&self) -> VectorN<Scalar, Dimension>;
+    // the hessian approximation, for the searchers that keep one
+    fn hessian(&self) -> Option<MatrixN<Scalar, Dimension>>
+    where
+        DefaultAllocator: Allocator<Scalar, Dimension, Dimension>,
+    {
+        None
+    }
+}
+
+// which block of coordinates the coordinate descent moves along next
+#[derive(Clone)]
+pub enum Selection<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: Dim,
+    DefaultAllocator: Allocator<Scalar, Dimension>,
+{
+    Cyclic,
+    // the block with the largest gradient norm
+    GaussSouthwell { df: Gradient<Scalar, Dimension> },
+    // the same with the gradient replaced by forward differences with step `h`
+    FiniteDifference { h: Scalar },
+    Random { seed: u64 },
+    // every block once per sweep, in an order shuffled for each sweep
+    Permutation { seed: u64 },
+}
+
+#[derive(Clone)]
+pub enum Update {
+    Bfgs,
+    Dfp,
+}
+
+#[derive(Clone)]
+pub enum Form {
+    InverseHessian,
+    Hessian,
+}
+
+#[derive(Clone)]
+pub enum CurvatureGuard {
+    Skip,
+    Damp,
+}
+
+#[derive(Clone)]
+pub enum Step<Scalar> {
+    Exact {
+        method: one_dimension_searchers::search::Method<Scalar>,
+        bracketing: Bracketing<Scalar>,
+    },
+    Constant(Scalar),
+    // `initial / (1 + decay * k)` on the k-th iteration
+    Diminishing {
+        initial: Scalar,
+        decay: Scalar,
+    },
+    // `initial` is used until there is a previous step to take the ratio from
+    BarzilaiBorwein {
+        initial: Scalar,
+    },
+}
+
+#[derive(Clone)]
+pub enum Modification<Scalar> {
+    // eigenvalues of the hessian below `delta` are raised to `delta`
+    Eigenvalue { delta: Scalar },
+    // `tau * I` is added to the hessian, `tau` starts from `beta` and doubles until the cholesky factorisation succeeds
+    CholeskyShift { beta: Scalar },
+}
+
+#[derive(Clone)]
+pub enum Exploration<Scalar> {
+    // a successful step is multiplied by `expansion`, a failed one by `-contraction`
+    Discrete {
+        step: Scalar,
+        expansion: Scalar,
+        contraction: Scalar,
+    },
+    LineSearch {
+        line_search: line_searchers::search::Method<Scalar>,
+    },
+}
+
+#[derive(Clone)]
+pub enum Coefficients<Scalar> {
+    Fixed {
+        reflection: Scalar,
+        expansion: Scalar,
+        contraction: Scalar,
+        shrink: Scalar,
+    },
+    // depend on the dimension n: 1, 1 + 2 / n, 3 / 4 - 1 / 2n and 1 - 1 / n,
+    // below two dimensions the shrink would vanish, so the usual 1, 2, 1 / 2 and 1 / 2 are used
+    Adaptive,
+}
+
+#[derive(Clone)]
+pub enum Subproblem {
+    Cauchy,
+    Dogleg,
+    Steihaug,
+}
+
+// the radius shrinks when the ratio `rho` of actual to predicted reduction is below 1/4 and grows above 3/4
+#[derive(Clone)]
+pub enum RadiusUpdate<Scalar> {
+    // quarters the radius or doubles it when the step reaches the boundary
+    Classic {
+        max: Scalar,
+    },
+    // sets the radius to `shrink` or `expand` times the length of the step
+    StepNorm {
+        shrink: Scalar,
+        expand: Scalar,
+        max: Scalar,
+    },
+}
+
+#[derive(Clone)]
+pub enum Beta {
+    FletcherReeves,
+    PolakRibierePlus,
+    HestenesStiefel,
+    DaiYuan,
 }
 
+// the lipschitz constant `L` of the gradient, accelerated gradient steps have length `1 / L`
 #[derive(Clone)]
-pub enum Method {
-    Gauss,
+pub enum LipschitzEstimate<Scalar> {
+    Known(Scalar),
+    // the estimate is multiplied by `factor > 1` until the step decreases the function enough
+    Backtracking { initial: Scalar, factor: Scalar },
+}
+
+// the momentum is dropped when the function value grows, or when the gradient makes an acute angle with the step
+#[derive(Clone)]
+pub enum Restart {
+    FunctionValue,
+    Gradient,
+}
+
+// initial inverse hessian of the limited-memory two-loop recursion
+#[derive(Clone)]
+pub enum Scaling<Scalar> {
+    Identity,
+    ShannoPhua,
+    Constant(Scalar),
+}
+
+#[derive(Clone)]
+pub enum Method<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: Dim,
+    DefaultAllocator: Allocator<Scalar, Dimension> + Allocator<Scalar, Dimension, Dimension>,
+{
+    Gauss {
+        line_search: line_searchers::search::Method<Scalar>,
+        selection: Selection<Scalar, Dimension>,
+        // groups of coordinate indices, every coordinate is a block of its own when `None`
+        blocks: Option<Vec<Vec<usize>>>,
+    },
+    Broyden {
+        df: Gradient<Scalar, Dimension>,
+        line_search: line_searchers::search::Method<Scalar>,
+        restart: bool,
+    },
+    Bfgs {
+        df: Gradient<Scalar, Dimension>,
+        line_search: line_searchers::search::Method<Scalar>,
+        form: Form,
+        guard: CurvatureGuard,
+        reset: Option<NonZeroUsize>,
+    },
+    Dfp {
+        df: Gradient<Scalar, Dimension>,
+        line_search: line_searchers::search::Method<Scalar>,
+        form: Form,
+        guard: CurvatureGuard,
+        reset: Option<NonZeroUsize>,
+    },
+    LBfgs {
+        df: Gradient<Scalar, Dimension>,
+        line_search: line_searchers::search::Method<Scalar>,
+        memory: usize,
+        scaling: Scaling<Scalar>,
+    },
+    ConjugateGradient {
+        df: Gradient<Scalar, Dimension>,
+        line_search: line_searchers::search::Method<Scalar>,
+        beta: Beta,
+        restart: bool,
+    },
+    SteepestDescent {
+        df: Gradient<Scalar, Dimension>,
+        step: Step<Scalar>,
+    },
+    // `x' = x - alpha * g + beta * (x - x_prev)`
+    HeavyBall {
+        df: Gradient<Scalar, Dimension>,
+        alpha: Scalar,
+        beta: Scalar,
+        restart: Option<Restart>,
+    },
+    Nesterov {
+        df: Gradient<Scalar, Dimension>,
+        lipschitz: LipschitzEstimate<Scalar>,
+        restart: Option<Restart>,
+    },
+    // `line_search: None` takes the full newton step
+    Newton {
+        df: Gradient<Scalar, Dimension>,
+        ddf: Hessian<Scalar, Dimension>,
+        line_search: Option<line_searchers::search::Method<Scalar>>,
+        modification: Option<Modification<Scalar>>,
+    },
+    // `step` is multiplied by `reduction` whenever the exploration around the base point fails
+    HookeJeeves {
+        step: Scalar,
+        reduction: Scalar,
+    },
+    Rosenbrock {
+        exploration: Exploration<Scalar>,
+    },
+    Powell {
+        method: one_dimension_searchers::search::Method<Scalar>,
+        bracketing: Bracketing<Scalar>,
+    },
+    // the initial simplex is `x0` and `x0 + size * e_i`
+    NelderMead {
+        coefficients: Coefficients<Scalar>,
+        size: Scalar,
+    },
+    // steps with `rho <= eta` are rejected
+    TrustRegion {
+        df: Gradient<Scalar, Dimension>,
+        ddf: Hessian<Scalar, Dimension>,
+        subproblem: Subproblem,
+        radius: Scalar,
+        update: RadiusUpdate<Scalar>,
+        eta: Scalar,
+    },
 }
 
 pub struct Search<Scalar, Dimension>
 where
     Scalar: RealField,
-    Dimension: DimName,
+    Dimension: Dim,
     DefaultAllocator: Allocator<Scalar, Dimension>,
 {
     x: VectorN<Scalar, Dimension>,
     dx: VectorN<Scalar, Dimension>,
     func_calls: usize,
+    derivative_calls: usize,
+    hessian_calls: usize,
+    factorizations: usize,
     iters: usize,
     method: Box<
         dyn DescentSearcher<Scalar, Dimension, Item = IterationResult<VectorN<Scalar, Dimension>>>,
@@ -38,59 +290,283 @@ where
 impl<Scalar, Dimension> Search<Scalar, Dimension>
 where
     Scalar: RealField,
-    Dimension: DimName,
-    DefaultAllocator: Allocator<Scalar, Dimension>,
+    Dimension: DimSub<Dynamic>,
+    DefaultAllocator: Allocator<Scalar, Dimension> + Allocator<Scalar, Dimension, Dimension>,
 {
     pub fn new(
         x0: VectorN<Scalar, Dimension>,
         f: Arc<dyn Fn(VectorN<Scalar, Dimension>) -> Scalar>,
         comparator: std::cmp::Ordering,
-        method: Method,
+        method: Method<Scalar, Dimension>,
         eps: Scalar,
         max_iters: usize,
     ) -> Self {
-        let m = match method {
-            Method::Gauss => super::gauss::Gauss::new(x0.clone(), f, comparator, eps, max_iters),
+        let m: Box<
+            dyn DescentSearcher<
+                Scalar,
+                Dimension,
+                Item = IterationResult<VectorN<Scalar, Dimension>>,
+            >,
+        > = match method {
+            Method::Gauss {
+                line_search,
+                selection,
+                blocks,
+            } => Box::new(super::gauss::Gauss::new(
+                x0.clone(),
+                f,
+                comparator,
+                line_search,
+                selection,
+                blocks,
+                eps,
+                max_iters,
+            )),
+            Method::Broyden {
+                df,
+                line_search,
+                restart,
+            } => Box::new(super::broyden::Broyden::new(
+                x0.clone(),
+                f,
+                df,
+                comparator,
+                line_search,
+                restart,
+                eps,
+                max_iters,
+            )),
+            Method::Bfgs {
+                df,
+                line_search,
+                form,
+                guard,
+                reset,
+            } => Box::new(super::variable_metric::VariableMetric::new(
+                x0.clone(),
+                f,
+                df,
+                comparator,
+                line_search,
+                Update::Bfgs,
+                form,
+                guard,
+                reset,
+                eps,
+                max_iters,
+            )),
+            Method::Dfp {
+                df,
+                line_search,
+                form,
+                guard,
+                reset,
+            } => Box::new(super::variable_metric::VariableMetric::new(
+                x0.clone(),
+                f,
+                df,
+                comparator,
+                line_search,
+                Update::Dfp,
+                form,
+                guard,
+                reset,
+                eps,
+                max_iters,
+            )),
+            Method::LBfgs {
+                df,
+                line_search,
+                memory,
+                scaling,
+            } => Box::new(super::lbfgs::LBfgs::new(
+                x0.clone(),
+                f,
+                df,
+                comparator,
+                line_search,
+                memory,
+                scaling,
+                eps,
+                max_iters,
+            )),
+            Method::ConjugateGradient {
+                df,
+                line_search,
+                beta,
+                restart,
+            } => Box::new(super::conjugate_gradient::ConjugateGradient::new(
+                x0.clone(),
+                f,
+                df,
+                comparator,
+                line_search,
+                beta,
+                restart,
+                eps,
+                max_iters,
+            )),
+            Method::SteepestDescent { df, step } => {
+                Box::new(super::steepest_descent::SteepestDescent::new(
+                    x0.clone(),
+                    f,
+                    df,
+                    comparator,
+                    step,
+                    eps,
+                    max_iters,
+                ))
+            }
+            Method::HeavyBall {
+                df,
+                alpha,
+                beta,
+                restart,
+            } => Box::new(super::heavy_ball::HeavyBall::new(
+                x0.clone(),
+                f,
+                df,
+                comparator,
+                alpha,
+                beta,
+                restart,
+                eps,
+                max_iters,
+            )),
+            Method::Nesterov {
+                df,
+                lipschitz,
+                restart,
+            } => Box::new(super::nesterov::Nesterov::new(
+                x0.clone(),
+                f,
+                df,
+                comparator,
+                lipschitz,
+                restart,
+                eps,
+                max_iters,
+            )),
+            Method::Newton {
+                df,
+                ddf,
+                line_search,
+                modification,
+            } => Box::new(super::newton::Newton::new(
+                x0.clone(),
+                f,
+                df,
+                ddf,
+                comparator,
+                line_search,
+                modification,
+                eps,
+                max_iters,
+            )),
+            Method::HookeJeeves { step, reduction } => {
+                Box::new(super::hooke_jeeves::HookeJeeves::new(
+                    x0.clone(),
+                    f,
+                    comparator,
+                    step,
+                    reduction,
+                    eps,
+                    max_iters,
+                ))
+            }
+            Method::Rosenbrock { exploration } => Box::new(super::rosenbrock::Rosenbrock::new(
+                x0.clone(),
+                f,
+                comparator,
+                exploration,
+                eps,
+                max_iters,
+            )),
+            Method::Powell { method, bracketing } => Box::new(super::powell::Powell::new(
+                x0.clone(),
+                f,
+                comparator,
+                method,
+                bracketing,
+                eps,
+                max_iters,
+            )),
+            Method::NelderMead { coefficients, size } => {
+                Box::new(super::nelder_mead::NelderMead::new(
+                    x0.clone(),
+                    f,
+                    comparator,
+                    coefficients,
+                    size,
+                    eps,
+                    max_iters,
+                ))
+            }
+            Method::TrustRegion {
+                df,
+                ddf,
+                subproblem,
+                radius,
+                update,
+                eta,
+            } => Box::new(super::trust_region::TrustRegion::new(
+                x0.clone(),
+                f,
+                df,
+                ddf,
+                comparator,
+                subproblem,
+                radius,
+                update,
+                eta,
+                eps,
+                max_iters,
+            )),
         };
-        use crate::searchers::extremum_searcher::Search;
         Self {
+            dx: x0.map(|_| Scalar::max_value()),
             x: x0,
-            dx: VectorN::<Scalar, Dimension>::from_element(Scalar::max_value()),
             func_calls: m.func_calls(),
+            derivative_calls: m.derivative_calls(),
+            hessian_calls: m.hessian_calls(),
+            factorizations: m.factorizations(),
             iters: 0,
-            method: Box::new(m),
+            method: m,
         }
     }
     pub fn result(
         x0: VectorN<Scalar, Dimension>,
         f: Arc<dyn Fn(VectorN<Scalar, Dimension>) -> Scalar>,
         comparator: std::cmp::Ordering,
-        method: Method,
+        method: Method<Scalar, Dimension>,
         eps: Scalar,
         max_iters: usize,
-    ) -> FinalResult<VectorN<Scalar, Dimension>> {
+    ) -> Result<FinalResult<VectorN<Scalar, Dimension>>, SearchError> {
         use crate::searchers::extremum_searcher::Search;
-        Self::new(x0, f, comparator, method, eps, max_iters).result()
+        Self::new(x0, f, comparator, method, eps, max_iters).try_result()
     }
     pub fn Mnimimum(
         x0: VectorN<Scalar, Dimension>,
         f: Arc<dyn Fn(VectorN<Scalar, Dimension>) -> Scalar>,
-        method: Method,
+        method: Method<Scalar, Dimension>,
         eps: Scalar,
         max_iters: usize,
-    ) -> FinalResult<VectorN<Scalar, Dimension>> {
+    ) -> Result<FinalResult<VectorN<Scalar, Dimension>>, SearchError> {
         use crate::searchers::extremum_searcher::Search;
-        Self::new(x0, f, std::cmp::Ordering::Less, method, eps, max_iters).result()
+        Self::new(x0, f, std::cmp::Ordering::Less, method, eps, max_iters).try_result()
     }
     pub fn Maximum(
         x0: VectorN<Scalar, Dimension>,
         f: Arc<dyn Fn(VectorN<Scalar, Dimension>) -> Scalar>,
-        method: Method,
+        method: Method<Scalar, Dimension>,
         eps: Scalar,
         max_iters: usize,
-    ) -> FinalResult<VectorN<Scalar, Dimension>> {
+    ) -> Result<FinalResult<VectorN<Scalar, Dimension>>, SearchError> {
         use crate::searchers::extremum_searcher::Search;
-        Self::new(x0, f, std::cmp::Ordering::Greater, method, eps, max_iters).result()
+        Self::new(x0, f, std::cmp::Ordering::Greater, method, eps, max_iters).try_result()
+    }
+    pub fn hessian(&self) -> Option<MatrixN<Scalar, Dimension>> {
+        self.method.hessian()
     }
 }
 
@@ -98,7 +574,7 @@ impl<Scalar, Dimension> extremum_searcher::Search<VectorN<Scalar, Dimension>>
     for Search<Scalar, Dimension>
 where
     Scalar: RealField,
-    Dimension: DimName,
+    Dimension: Dim,
     DefaultAllocator: Allocator<Scalar, Dimension>,
 {
     fn iters(&self) -> usize {
@@ -107,6 +583,18 @@ where
     fn func_calls(&self) -> usize {
         self.func_calls
     }
+    fn derivative_calls(&self) -> usize {
+        self.derivative_calls
+    }
+    fn hessian_calls(&self) -> usize {
+        self.hessian_calls
+    }
+    fn factorizations(&self) -> usize {
+        self.factorizations
+    }
+    fn error(&self) -> Option<SearchError> {
+        self.method.error()
+    }
     fn x(&self) -> VectorN<Scalar, Dimension> {
         self.x.clone()
     }
@@ -118,7 +606,7 @@ where
 impl<Scalar, Dimension> Iterator for Search<Scalar, Dimension>
 where
     Scalar: RealField,
-    Dimension: DimName,
+    Dimension: Dim,
     DefaultAllocator: Allocator<Scalar, Dimension>,
 {
     type Item = IterationResult<VectorN<Scalar, Dimension>>;
@@ -127,16 +615,123 @@ where
             Some(r) => {
                 self.iters += 1;
                 self.func_calls += r.func_calls();
+                self.derivative_calls += r.derivative_calls();
+                self.hessian_calls += r.hessian_calls();
+                self.factorizations += r.factorizations();
                 self.x = r.x();
                 self.dx = r.dx();
-                Some(IterationResult::new(
-                    self.x.clone(),
-                    self.dx.clone(),
-                    r.func_calls(),
-                    r.is_extra(),
-                ))
+                Some(r)
             }
             None => None,
         }
     }
 }
+
+#[cfg(test)]
+type Quadratic = Arc<dyn Fn(nalgebra::Vector2<f64>) -> f64>;
+
+// the test objective of the gradient searchers, the hessian is [[8, 1], [1, 2]] and the minimum
+// is at (4 / 3, -8 / 3)
+#[cfg(test)]
+pub fn quadratic() -> (Quadratic, Gradient<f64, nalgebra::U2>) {
+    (
+        Arc::new(|x| 4.0 * (x[0] - 1.0).powi(2) + (x[1] + 2.0).powi(2) + x[0] * x[1]),
+        Arc::new(|x| nalgebra::Vector2::new(8.0 * (x[0] - 1.0) + x[1], 2.0 * (x[1] + 2.0) + x[0])),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::searchers::one_dimension_searchers::search::BracketingError;
+    use nalgebra::{Vector2, U2};
+
+    #[test]
+    fn bracketing_failure_reaches_the_caller() {
+        let f: Arc<dyn Fn(Vector2<f64>) -> f64> = Arc::new(|x| x[0] + x[1]);
+        let result = Search::<f64, U2>::Mnimimum(
+            Vector2::new(1.0, 1.0),
+            f,
+            Method::Gauss {
+                line_search: line_searchers::search::Method::Exact {
+                    method: one_dimension_searchers::search::Method::GoldenRatio,
+                    bracketing: Bracketing::new(0.1, 2.0, 20, None),
+                },
+                selection: Selection::Cyclic,
+                blocks: None,
+            },
+            1e-6,
+            100,
+        );
+        assert_eq!(
+            result.err(),
+            Some(SearchError::Bracketing(BracketingError::UnboundedDirection))
+        );
+    }
+
+    #[test]
+    fn default_bracketing_reaches_a_distant_minimum() {
+        let f: Arc<dyn Fn(Vector2<f64>) -> f64> =
+            Arc::new(|x| (x[0] - 1000.0).powi(2) + (x[1] + 2000.0).powi(2));
+        let result = Search::<f64, U2>::Mnimimum(
+            Vector2::new(0.0, 0.0),
+            f,
+            Method::Gauss {
+                line_search: line_searchers::search::Method::Exact {
+                    method: one_dimension_searchers::search::Method::GoldenRatio,
+                    bracketing: Bracketing::default(),
+                },
+                selection: Selection::Cyclic,
+                blocks: None,
+            },
+            1e-6,
+            100,
+        )
+        .unwrap();
+        assert!((result.x() - Vector2::new(1000.0, -2000.0)).amax() < 1e-3);
+    }
+
+    #[test]
+    fn minimises_a_separable_quadratic() {
+        let f: Arc<dyn Fn(Vector2<f64>) -> f64> =
+            Arc::new(|x| (x[0] - 1.0).powi(2) + (x[1] + 2.0).powi(2));
+        let result = Search::<f64, U2>::Mnimimum(
+            Vector2::new(0.0, 0.0),
+            f,
+            Method::Gauss {
+                line_search: line_searchers::search::Method::Exact {
+                    method: one_dimension_searchers::search::Method::GoldenRatio,
+                    bracketing: Bracketing::default(),
+                },
+                selection: Selection::Cyclic,
+                blocks: None,
+            },
+            1e-6,
+            100,
+        )
+        .unwrap();
+        assert!((result.x() - Vector2::new(1.0, -2.0)).amax() < 1e-3);
+    }
+
+    #[test]
+    fn maximum_is_the_minimum_of_the_negated_function() {
+        let f: Arc<dyn Fn(Vector2<f64>) -> f64> =
+            Arc::new(|x| -(x[0] - 1.0).powi(2) - (x[1] + 2.0).powi(2));
+        let result = Search::<f64, U2>::Maximum(
+            Vector2::new(0.0, 0.0),
+            f,
+            Method::Gauss {
+                line_search: line_searchers::search::Method::Exact {
+                    method: one_dimension_searchers::search::Method::GoldenRatio,
+                    bracketing: Bracketing::default(),
+                },
+                selection: Selection::Cyclic,
+                blocks: None,
+            },
+            1e-6,
+            100,
+        )
+        .unwrap();
+        assert!((result.x() - Vector2::new(1.0, -2.0)).amax() < 1e-3);
+    }
+}
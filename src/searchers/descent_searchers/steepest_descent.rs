@@ -0,0 +1,281 @@
+use super::search::{DescentSearcher, Gradient, Step};
+use crate::searchers::extremum_searcher;
+use crate::searchers::extremum_searcher::{IterationResult, SearchError};
+use crate::searchers::line_searchers;
+use nalgebra::{allocator::Allocator, DefaultAllocator, Dim, RealField, VectorN};
+use std::sync::Arc;
+
+pub struct SteepestDescent<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: Dim,
+    DefaultAllocator: Allocator<Scalar, Dimension>,
+{
+    x: VectorN<Scalar, Dimension>,
+    dx: VectorN<Scalar, Dimension>,
+    g: VectorN<Scalar, Dimension>,
+    sign: Scalar,
+    func_calls: usize,
+    derivative_calls: usize,
+    iters: usize,
+    error: Option<SearchError>,
+    f: Arc<dyn Fn(VectorN<Scalar, Dimension>) -> Scalar>,
+    df: Gradient<Scalar, Dimension>,
+    eps: Scalar,
+    max_iters: usize,
+    comparator: std::cmp::Ordering,
+    step: Step<Scalar>,
+    // previous step and gradient change, used by Barzilai-Borwein
+    sy: Option<(Scalar, Scalar)>,
+}
+
+impl<Scalar, Dimension> SteepestDescent<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: Dim,
+    DefaultAllocator: Allocator<Scalar, Dimension>,
+{
+    pub fn new(
+        x0: VectorN<Scalar, Dimension>,
+        f: Arc<dyn Fn(VectorN<Scalar, Dimension>) -> Scalar>,
+        df: Gradient<Scalar, Dimension>,
+        comparator: std::cmp::Ordering,
+        step: Step<Scalar>,
+        eps: Scalar,
+        max_iters: usize,
+    ) -> Self {
+        // the gradient of `sign * f` is kept, so `S` always points downhill
+        let sign = if comparator == std::cmp::Ordering::Less {
+            Scalar::one()
+        } else {
+            -Scalar::one()
+        };
+        let g = df(x0.clone()) * sign;
+        Self {
+            dx: x0.map(|_| Scalar::max_value()),
+            x: x0,
+            g,
+            sign,
+            f,
+            df,
+            eps,
+            iters: 0,
+            error: None,
+            func_calls: 0,
+            derivative_calls: 1,
+            max_iters,
+            comparator,
+            step,
+            sy: None,
+        }
+    }
+
+    // returns the step length along `S` with the function calls and derivative calls it took
+    fn lambda(&self) -> Result<(Scalar, usize, usize), SearchError> {
+        match &self.step {
+            Step::Exact { method, bracketing } => {
+                let x = self.x.clone();
+                let f = self.f.clone();
+                let s = self.S();
+                let (_x, _s) = (x.clone(), s.clone());
+                let df = self.df.clone();
+                let result = line_searchers::search::Search::result(
+                    Arc::new(move |lambda| f(x.clone() + s.clone() * lambda)),
+                    Some(Arc::new(move |lambda| {
+                        df(_x.clone() + _s.clone() * lambda).dot(&_s)
+                    })),
+                    self.comparator,
+                    line_searchers::search::Method::Exact {
+                        method: method.clone(),
+                        bracketing: bracketing.clone(),
+                    },
+                    Scalar::one(),
+                    self.eps,
+                    self.max_iters,
+                )?;
+                Ok((result.x(), result.func_calls(), result.derivative_calls()))
+            }
+            Step::Constant(lambda) => Ok((*lambda, 0, 0)),
+            Step::Diminishing { initial, decay } => Ok((
+                *initial / (Scalar::one() + *decay * Scalar::from_usize(self.iters).unwrap()),
+                0,
+                0,
+            )),
+            Step::BarzilaiBorwein { initial } => match self.sy {
+                Some((ss, sy)) if sy > Scalar::zero() => Ok((ss / sy, 0, 0)),
+                _ => Ok((*initial, 0, 0)),
+            },
+        }
+    }
+}
+
+impl<Scalar, Dimension> DescentSearcher<Scalar, Dimension> for SteepestDescent<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: Dim,
+    DefaultAllocator: Allocator<Scalar, Dimension>,
+{
+    fn S(&self) -> VectorN<Scalar, Dimension> {
+        -self.g.clone()
+    }
+}
+
+impl<Scalar, Dimension> extremum_searcher::Search<VectorN<Scalar, Dimension>>
+    for SteepestDescent<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: Dim,
+    DefaultAllocator: Allocator<Scalar, Dimension>,
+{
+    fn iters(&self) -> usize {
+        self.iters
+    }
+    fn error(&self) -> Option<SearchError> {
+        self.error
+    }
+    fn func_calls(&self) -> usize {
+        self.func_calls
+    }
+    fn derivative_calls(&self) -> usize {
+        self.derivative_calls
+    }
+    fn x(&self) -> VectorN<Scalar, Dimension> {
+        self.x.clone()
+    }
+    fn dx(&self) -> VectorN<Scalar, Dimension> {
+        self.dx.clone()
+    }
+}
+
+impl<Scalar, Dimension> Iterator for SteepestDescent<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: Dim,
+    DefaultAllocator: Allocator<Scalar, Dimension>,
+{
+    type Item = IterationResult<VectorN<Scalar, Dimension>>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let is_extra = self.g.norm() < self.eps
+            || self.dx.iter().all(|xi| xi.abs() < self.eps)
+            || self.iters >= self.max_iters;
+        // nothing is searched once the search is over, the direction may no longer descend
+        if is_extra {
+            return Some(IterationResult::new(
+                self.x.clone(),
+                self.x.map(|_| Scalar::zero()),
+                0,
+                true,
+            ));
+        }
+        let (lambda, func_calls, derivative_calls) = match self.lambda() {
+            Ok(lambda) => lambda,
+            Err(error) => {
+                self.error = Some(error);
+                return None;
+            }
+        };
+        self.dx = self.S() * lambda;
+        self.x += self.dx.clone();
+        self.iters += 1;
+        let dg = (self.df)(self.x.clone()) * self.sign - self.g.clone();
+        self.g += dg.clone();
+        self.sy = Some((self.dx.norm_squared(), self.dx.dot(&dg)));
+
+        self.func_calls += func_calls;
+        self.derivative_calls += derivative_calls + 1;
+        Some(
+            IterationResult::new(self.x.clone(), self.dx.clone(), func_calls, is_extra)
+                .with_derivative_calls(derivative_calls + 1),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::searchers::descent_searchers::search::{quadratic, Method, Search};
+    use crate::searchers::one_dimension_searchers;
+    use crate::searchers::one_dimension_searchers::search::Bracketing;
+    use nalgebra::Vector2;
+
+    fn steps() -> Vec<Step<f64>> {
+        vec![
+            Step::Exact {
+                method: one_dimension_searchers::search::Method::Brent,
+                bracketing: Bracketing::default(),
+            },
+            Step::Constant(0.1),
+            Step::Diminishing {
+                initial: 0.2,
+                decay: 1e-3,
+            },
+            Step::BarzilaiBorwein { initial: 0.01 },
+        ]
+    }
+
+    #[test]
+    fn every_step_rule_minimises_a_quadratic() {
+        for step in steps() {
+            let (f, df) = quadratic();
+            let result = Search::Mnimimum(
+                Vector2::new(-3.0, 4.0),
+                f,
+                Method::SteepestDescent { df, step },
+                1e-9,
+                10000,
+            )
+            .unwrap();
+            assert!((result.x() - Vector2::new(4.0 / 3.0, -8.0 / 3.0)).norm() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn maximises_with_the_greater_comparator() {
+        let result = Search::Maximum(
+            Vector2::new(-3.0, 4.0),
+            Arc::new(|x: Vector2<f64>| -(x[0] - 1.0).powi(2) - 3.0 * (x[1] - 2.0).powi(2)),
+            Method::SteepestDescent {
+                df: Arc::new(|x| Vector2::new(-2.0 * (x[0] - 1.0), -6.0 * (x[1] - 2.0))),
+                step: Step::BarzilaiBorwein { initial: 0.1 },
+            },
+            1e-9,
+            1000,
+        )
+        .unwrap();
+        assert!((result.x() - Vector2::new(1.0, 2.0)).norm() < 1e-6);
+    }
+
+    #[test]
+    fn direction_is_the_negated_gradient() {
+        let (f, df) = quadratic();
+        let mut searcher = SteepestDescent::new(
+            Vector2::new(-3.0, 4.0),
+            f,
+            df.clone(),
+            std::cmp::Ordering::Less,
+            Step::Constant(0.1),
+            1e-9,
+            100,
+        );
+        assert_eq!(searcher.S(), -df(Vector2::new(-3.0, 4.0)));
+        let r = searcher.next().unwrap();
+        assert!((searcher.S() + df(r.x())).norm() < 1e-12);
+    }
+
+    #[test]
+    fn stops_on_the_gradient_norm() {
+        let (f, df) = quadratic();
+        let result = Search::Mnimimum(
+            Vector2::new(4.0 / 3.0, -8.0 / 3.0),
+            f,
+            Method::SteepestDescent {
+                df,
+                step: Step::Constant(0.1),
+            },
+            1e-9,
+            100,
+        )
+        .unwrap();
+        assert_eq!(result.iters(), 0);
+    }
+}
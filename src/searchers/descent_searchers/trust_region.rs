@@ -0,0 +1,438 @@
+use super::search::{DescentSearcher, Gradient, Hessian, RadiusUpdate, Subproblem};
+use crate::searchers::extremum_searcher;
+use crate::searchers::extremum_searcher::IterationResult;
+use nalgebra::{
+    allocator::Allocator, DefaultAllocator, DimSub, Dynamic, MatrixN, RealField, VectorN,
+};
+use std::sync::Arc;
+
+pub struct TrustRegion<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: DimSub<Dynamic>,
+    DefaultAllocator: Allocator<Scalar, Dimension> + Allocator<Scalar, Dimension, Dimension>,
+{
+    x: VectorN<Scalar, Dimension>,
+    dx: VectorN<Scalar, Dimension>,
+    fx: Scalar,
+    g: VectorN<Scalar, Dimension>,
+    h: MatrixN<Scalar, Dimension>,
+    // the step proposed by the last subproblem
+    p: VectorN<Scalar, Dimension>,
+    radius: Scalar,
+    // the ratio of the actual to the predicted decrease of the last step, if one was taken
+    rho: Option<Scalar>,
+    sign: Scalar,
+    func_calls: usize,
+    derivative_calls: usize,
+    hessian_calls: usize,
+    factorizations: usize,
+    iters: usize,
+    f: Arc<dyn Fn(VectorN<Scalar, Dimension>) -> Scalar>,
+    df: Gradient<Scalar, Dimension>,
+    ddf: Hessian<Scalar, Dimension>,
+    eps: Scalar,
+    max_iters: usize,
+    subproblem: Subproblem,
+    update: RadiusUpdate<Scalar>,
+    eta: Scalar,
+}
+
+impl<Scalar, Dimension> TrustRegion<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: DimSub<Dynamic>,
+    DefaultAllocator: Allocator<Scalar, Dimension> + Allocator<Scalar, Dimension, Dimension>,
+{
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        x0: VectorN<Scalar, Dimension>,
+        f: Arc<dyn Fn(VectorN<Scalar, Dimension>) -> Scalar>,
+        df: Gradient<Scalar, Dimension>,
+        ddf: Hessian<Scalar, Dimension>,
+        comparator: std::cmp::Ordering,
+        subproblem: Subproblem,
+        radius: Scalar,
+        update: RadiusUpdate<Scalar>,
+        eta: Scalar,
+        eps: Scalar,
+        max_iters: usize,
+    ) -> Self {
+        // the model is built for `sign * f`, which is always minimised
+        let sign = if comparator == std::cmp::Ordering::Less {
+            Scalar::one()
+        } else {
+            -Scalar::one()
+        };
+        let g = df(x0.clone()) * sign;
+        Self {
+            dx: x0.map(|_| Scalar::max_value()),
+            fx: f(x0.clone()) * sign,
+            p: -g.clone(),
+            g,
+            h: ddf(x0.clone()) * sign,
+            x: x0,
+            radius,
+            rho: None,
+            sign,
+            f,
+            df,
+            ddf,
+            eps,
+            iters: 0,
+            func_calls: 1,
+            derivative_calls: 1,
+            hessian_calls: 1,
+            factorizations: 0,
+            max_iters,
+            subproblem,
+            update,
+            eta,
+        }
+    }
+
+    pub fn radius(&self) -> Scalar {
+        self.radius
+    }
+    pub fn rho(&self) -> Option<Scalar> {
+        self.rho
+    }
+
+    // the largest `tau` with `|z + tau d| = radius`
+    fn to_boundary(
+        &self,
+        z: &VectorN<Scalar, Dimension>,
+        d: &VectorN<Scalar, Dimension>,
+    ) -> Scalar {
+        let two = Scalar::from_i8(2).unwrap();
+        let a = d.norm_squared();
+        let b = two * z.dot(d);
+        let c = z.norm_squared() - self.radius * self.radius;
+        let discriminant = (b * b - two * two * a * c).max(Scalar::zero());
+        (-b + discriminant.sqrt()) / (two * a)
+    }
+
+    fn cauchy(&self) -> (VectorN<Scalar, Dimension>, bool) {
+        let g_norm = self.g.norm();
+        if g_norm == Scalar::zero() {
+            return (self.g.clone(), false);
+        }
+        let gbg = self.g.dot(&(&self.h * &self.g));
+        let tau = if gbg <= Scalar::zero() {
+            Scalar::one()
+        } else {
+            (g_norm.powi(3) / (self.radius * gbg)).min(Scalar::one())
+        };
+        (
+            &self.g * (-tau * self.radius / g_norm),
+            tau == Scalar::one(),
+        )
+    }
+
+    // falls back to the cauchy point while the hessian is not positive definite
+    fn dogleg(&mut self) -> (VectorN<Scalar, Dimension>, bool) {
+        self.factorizations += 1;
+        let newton = match self.h.clone().cholesky() {
+            Some(cholesky) => -cholesky.solve(&self.g),
+            None => return self.cauchy(),
+        };
+        if newton.norm() <= self.radius {
+            return (newton, false);
+        }
+        let gbg = self.g.dot(&(&self.h * &self.g));
+        let steepest = &self.g * (-self.g.norm_squared() / gbg);
+        if steepest.norm() >= self.radius {
+            return (&self.g * (-self.radius / self.g.norm()), true);
+        }
+        let d = newton - &steepest;
+        let tau = self.to_boundary(&steepest, &d);
+        (steepest + d * tau, true)
+    }
+
+    fn steihaug(&self) -> (VectorN<Scalar, Dimension>, bool) {
+        let g_norm = self.g.norm();
+        if g_norm == Scalar::zero() {
+            return (self.g.clone(), false);
+        }
+        let tolerance = g_norm * g_norm.sqrt().min(Scalar::from_f64(0.5).unwrap());
+        let mut z = self.g.map(|_| Scalar::zero());
+        let mut r = self.g.clone();
+        let mut d = -self.g.clone();
+        for _ in 0..self.x.len() {
+            let bd = &self.h * &d;
+            let dbd = d.dot(&bd);
+            if dbd <= Scalar::zero() {
+                let tau = self.to_boundary(&z, &d);
+                return (z + d * tau, true);
+            }
+            let alpha = r.norm_squared() / dbd;
+            let _z = &z + &d * alpha;
+            if _z.norm() >= self.radius {
+                let tau = self.to_boundary(&z, &d);
+                return (z + d * tau, true);
+            }
+            let _r = &r + bd * alpha;
+            if _r.norm() < tolerance {
+                return (_z, false);
+            }
+            let beta = _r.norm_squared() / r.norm_squared();
+            d = &d * beta - &_r;
+            z = _z;
+            r = _r;
+        }
+        (z, false)
+    }
+
+    fn update_radius(&mut self, rho: Scalar, step: Scalar, on_boundary: bool) {
+        let quarter = Scalar::from_f64(0.25).unwrap();
+        let three_quarters = Scalar::from_f64(0.75).unwrap();
+        match &self.update {
+            RadiusUpdate::Classic { max } => {
+                if rho < quarter {
+                    self.radius *= quarter;
+                } else if rho > three_quarters && on_boundary {
+                    self.radius = (self.radius * Scalar::from_i8(2).unwrap()).min(*max);
+                }
+            }
+            RadiusUpdate::StepNorm {
+                shrink,
+                expand,
+                max,
+            } => {
+                if rho < quarter {
+                    self.radius = step * *shrink;
+                } else if rho > three_quarters {
+                    self.radius = self.radius.max(step * *expand).min(*max);
+                }
+            }
+        }
+    }
+}
+
+impl<Scalar, Dimension> DescentSearcher<Scalar, Dimension> for TrustRegion<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: DimSub<Dynamic>,
+    DefaultAllocator: Allocator<Scalar, Dimension> + Allocator<Scalar, Dimension, Dimension>,
+{
+    // the step of the last iteration, whether it was accepted or not, the antigradient before the first
+    fn S(&self) -> VectorN<Scalar, Dimension> {
+        self.p.clone()
+    }
+}
+
+impl<Scalar, Dimension> extremum_searcher::Search<VectorN<Scalar, Dimension>>
+    for TrustRegion<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: DimSub<Dynamic>,
+    DefaultAllocator: Allocator<Scalar, Dimension> + Allocator<Scalar, Dimension, Dimension>,
+{
+    fn iters(&self) -> usize {
+        self.iters
+    }
+    fn func_calls(&self) -> usize {
+        self.func_calls
+    }
+    fn derivative_calls(&self) -> usize {
+        self.derivative_calls
+    }
+    fn hessian_calls(&self) -> usize {
+        self.hessian_calls
+    }
+    fn factorizations(&self) -> usize {
+        self.factorizations
+    }
+    fn x(&self) -> VectorN<Scalar, Dimension> {
+        self.x.clone()
+    }
+    fn dx(&self) -> VectorN<Scalar, Dimension> {
+        self.dx.clone()
+    }
+}
+
+impl<Scalar, Dimension> Iterator for TrustRegion<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: DimSub<Dynamic>,
+    DefaultAllocator: Allocator<Scalar, Dimension> + Allocator<Scalar, Dimension, Dimension>,
+{
+    type Item = IterationResult<VectorN<Scalar, Dimension>>;
+    fn next(&mut self) -> Option<Self::Item> {
+        // rejected steps leave `dx` at zero, so the radius is checked instead
+        let is_extra =
+            self.g.norm() < self.eps || self.radius < self.eps || self.iters >= self.max_iters;
+        let factorizations = self.factorizations;
+        let (p, on_boundary) = match self.subproblem {
+            Subproblem::Cauchy => self.cauchy(),
+            Subproblem::Dogleg => self.dogleg(),
+            Subproblem::Steihaug => self.steihaug(),
+        };
+        let factorizations = self.factorizations - factorizations;
+        self.p = p.clone();
+        // a model that cannot decrease leaves nothing to take, so the search stops where it is
+        let predicted = -(self.g.dot(&p) + (&self.h * &p).dot(&p) / Scalar::from_i8(2).unwrap());
+        if predicted <= Scalar::zero() {
+            self.dx = p.map(|_| Scalar::zero());
+            return Some(
+                IterationResult::new(self.x.clone(), self.dx.clone(), 0, true)
+                    .with_factorizations(factorizations),
+            );
+        }
+        let _x = self.x.clone() + &p;
+        let _fx = (self.f)(_x.clone()) * self.sign;
+        let rho = (self.fx - _fx) / predicted;
+        self.rho = Some(rho);
+        self.update_radius(rho, p.norm(), on_boundary);
+
+        let (mut derivative_calls, mut hessian_calls) = (0, 0);
+        if rho > self.eta {
+            self.x = _x;
+            self.fx = _fx;
+            self.g = (self.df)(self.x.clone()) * self.sign;
+            self.h = (self.ddf)(self.x.clone()) * self.sign;
+            self.dx = p;
+            derivative_calls = 1;
+            hessian_calls = 1;
+        } else {
+            self.dx = p.map(|_| Scalar::zero());
+        }
+        self.iters += 1;
+
+        self.func_calls += 1;
+        self.derivative_calls += derivative_calls;
+        self.hessian_calls += hessian_calls;
+        Some(
+            IterationResult::new(self.x.clone(), self.dx.clone(), 1, is_extra)
+                .with_derivative_calls(derivative_calls)
+                .with_hessian_calls(hessian_calls)
+                .with_factorizations(factorizations),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::searchers::descent_searchers::search::{Method, Search};
+    use nalgebra::{Matrix2, Vector2, U2};
+
+    type Function = Arc<dyn Fn(Vector2<f64>) -> f64>;
+
+    fn rosenbrock() -> (Function, Gradient<f64, U2>, Hessian<f64, U2>) {
+        (
+            Arc::new(|x| 100.0 * (x[1] - x[0] * x[0]).powi(2) + (1.0 - x[0]).powi(2)),
+            Arc::new(|x| {
+                Vector2::new(
+                    -400.0 * x[0] * (x[1] - x[0] * x[0]) - 2.0 * (1.0 - x[0]),
+                    200.0 * (x[1] - x[0] * x[0]),
+                )
+            }),
+            Arc::new(|x| {
+                Matrix2::new(
+                    1200.0 * x[0] * x[0] - 400.0 * x[1] + 2.0,
+                    -400.0 * x[0],
+                    -400.0 * x[0],
+                    200.0,
+                )
+            }),
+        )
+    }
+
+    fn method(subproblem: Subproblem) -> Method<f64, U2> {
+        let (_, df, ddf) = rosenbrock();
+        Method::TrustRegion {
+            df,
+            ddf,
+            subproblem,
+            radius: 1.0,
+            update: RadiusUpdate::Classic { max: 10.0 },
+            eta: 0.1,
+        }
+    }
+
+    #[test]
+    fn dogleg_and_steihaug_minimise_the_rosenbrock_function() {
+        for subproblem in [Subproblem::Dogleg, Subproblem::Steihaug] {
+            let (f, _, _) = rosenbrock();
+            let result =
+                Search::Mnimimum(Vector2::new(-1.2, 1.0), f, method(subproblem), 1e-10, 1000)
+                    .unwrap();
+            assert!((result.x() - Vector2::new(1.0, 1.0)).norm() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn step_norm_update_keeps_the_radius_below_its_maximum() {
+        let (f, df, ddf) = rosenbrock();
+        let mut searcher = TrustRegion::new(
+            Vector2::new(-1.2, 1.0),
+            f,
+            df,
+            ddf,
+            std::cmp::Ordering::Less,
+            Subproblem::Dogleg,
+            1.0,
+            RadiusUpdate::StepNorm {
+                shrink: 0.5,
+                expand: 2.0,
+                max: 2.0,
+            },
+            0.1,
+            1e-10,
+            1000,
+        );
+        let mut x = Vector2::new(-1.2, 1.0);
+        while let Some(r) = searcher.next() {
+            assert!(searcher.radius() <= 2.0);
+            x = r.x();
+            if r.is_extra() {
+                break;
+            }
+        }
+        assert!((x - Vector2::new(1.0, 1.0)).norm() < 1e-6);
+    }
+
+    #[test]
+    fn stops_without_a_nan_ratio_when_the_model_cannot_decrease() {
+        let (f, df, ddf) = rosenbrock();
+        let mut searcher = TrustRegion::new(
+            Vector2::new(1.0, 1.0),
+            f,
+            df,
+            ddf,
+            std::cmp::Ordering::Less,
+            Subproblem::Cauchy,
+            1.0,
+            RadiusUpdate::Classic { max: 10.0 },
+            0.1,
+            1e-10,
+            100,
+        );
+        let r = searcher.next().unwrap();
+        assert!(r.is_extra());
+        assert_eq!(searcher.rho(), None);
+        assert_eq!(r.x(), Vector2::new(1.0, 1.0));
+    }
+
+    #[test]
+    fn direction_is_the_step_taken() {
+        let (f, df, ddf) = rosenbrock();
+        let mut searcher = TrustRegion::new(
+            Vector2::new(1.1, 1.2),
+            f,
+            df,
+            ddf,
+            std::cmp::Ordering::Less,
+            Subproblem::Dogleg,
+            1.0,
+            RadiusUpdate::Classic { max: 10.0 },
+            0.0,
+            1e-10,
+            100,
+        );
+        let r = searcher.next().unwrap();
+        assert!(searcher.rho().unwrap() > 0.0);
+        assert_eq!(r.dx(), searcher.S());
+    }
+}
@@ -0,0 +1,442 @@
+use super::search::{CurvatureGuard, DescentSearcher, Form, Gradient, Update};
+use crate::searchers::extremum_searcher;
+use crate::searchers::extremum_searcher::{IterationResult, SearchError};
+use crate::searchers::line_searchers;
+use nalgebra::{
+    allocator::Allocator, DefaultAllocator, DimSub, Dynamic, MatrixN, RealField, VectorN,
+};
+use std::num::NonZeroUsize;
+use std::sync::Arc;
+
+pub struct VariableMetric<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: DimSub<Dynamic>,
+    DefaultAllocator: Allocator<Scalar, Dimension> + Allocator<Scalar, Dimension, Dimension>,
+{
+    x: VectorN<Scalar, Dimension>,
+    dx: VectorN<Scalar, Dimension>,
+    g: VectorN<Scalar, Dimension>,
+    s: VectorN<Scalar, Dimension>,
+    metric: MatrixN<Scalar, Dimension>,
+    sign: Scalar,
+    func_calls: usize,
+    derivative_calls: usize,
+    factorizations: usize,
+    iters: usize,
+    error: Option<SearchError>,
+    f: Arc<dyn Fn(VectorN<Scalar, Dimension>) -> Scalar>,
+    df: Gradient<Scalar, Dimension>,
+    eps: Scalar,
+    max_iters: usize,
+    comparator: std::cmp::Ordering,
+    line_search: line_searchers::search::Method<Scalar>,
+    update: Update,
+    form: Form,
+    guard: CurvatureGuard,
+    reset: Option<NonZeroUsize>,
+}
+
+impl<Scalar, Dimension> VariableMetric<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: DimSub<Dynamic>,
+    DefaultAllocator: Allocator<Scalar, Dimension> + Allocator<Scalar, Dimension, Dimension>,
+{
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        x0: VectorN<Scalar, Dimension>,
+        f: Arc<dyn Fn(VectorN<Scalar, Dimension>) -> Scalar>,
+        df: Gradient<Scalar, Dimension>,
+        comparator: std::cmp::Ordering,
+        line_search: line_searchers::search::Method<Scalar>,
+        update: Update,
+        form: Form,
+        guard: CurvatureGuard,
+        reset: Option<NonZeroUsize>,
+        eps: Scalar,
+        max_iters: usize,
+    ) -> Self {
+        // the metric is kept positive definite by approximating the hessian of `sign * f`
+        let sign = if comparator == std::cmp::Ordering::Less {
+            Scalar::one()
+        } else {
+            -Scalar::one()
+        };
+        let g = df(x0.clone()) * sign;
+        Self {
+            dx: x0.map(|_| Scalar::max_value()),
+            metric: Self::identity(x0.len()),
+            s: x0.map(|_| Scalar::zero()),
+            x: x0,
+            g,
+            sign,
+            f,
+            df,
+            eps,
+            iters: 0,
+            error: None,
+            func_calls: 0,
+            derivative_calls: 1,
+            factorizations: 0,
+            max_iters,
+            comparator,
+            line_search,
+            update,
+            form,
+            guard,
+            reset,
+        }
+    }
+
+    fn identity(n: usize) -> MatrixN<Scalar, Dimension> {
+        let n = Dimension::from_usize(n);
+        MatrixN::<Scalar, Dimension>::identity_generic(n, n)
+    }
+
+    // (I - a b' / a'b) m (I - b a' / a'b) + a a' / a'b
+    fn product_update(
+        m: &mut MatrixN<Scalar, Dimension>,
+        a: &VectorN<Scalar, Dimension>,
+        b: &VectorN<Scalar, Dimension>,
+    ) {
+        let rho = Scalar::one() / a.dot(b);
+        let mb = &*m * b;
+        let bmb = b.dot(&mb);
+        m.ger(-rho, &mb, a, Scalar::one());
+        m.ger(-rho, a, &mb, Scalar::one());
+        m.ger(rho + rho * rho * bmb, a, a, Scalar::one());
+    }
+
+    // m - m b b' m / b'm b + a a' / a'b
+    fn sum_update(
+        m: &mut MatrixN<Scalar, Dimension>,
+        a: &VectorN<Scalar, Dimension>,
+        b: &VectorN<Scalar, Dimension>,
+    ) {
+        let mb = &*m * b;
+        let bmb = b.dot(&mb);
+        let ab = a.dot(b);
+        m.ger(-Scalar::one() / bmb, &mb, &mb, Scalar::one());
+        m.ger(Scalar::one() / ab, a, a, Scalar::one());
+    }
+
+    fn update_metric(&mut self, s: VectorN<Scalar, Dimension>, y: VectorN<Scalar, Dimension>) {
+        // in the hessian form the roles of `s` and `y` are swapped
+        let (mut s, y) = match self.form {
+            Form::InverseHessian => (s, y),
+            Form::Hessian => (y, s),
+        };
+        let my = &self.metric * &y;
+        let ymy = y.dot(&my);
+        let sy = s.dot(&y);
+        let tiny = Scalar::default_epsilon() * s.norm() * y.norm();
+        match self.guard {
+            CurvatureGuard::Skip => {
+                if sy <= tiny {
+                    return;
+                }
+            }
+            CurvatureGuard::Damp => {
+                // Powell's damping keeps s'y >= 0.2 y'My
+                let bound = Scalar::from_f64(0.2).unwrap() * ymy;
+                if sy < bound {
+                    let theta = Scalar::from_f64(0.8).unwrap() * ymy / (ymy - sy);
+                    s = s * theta + my * (Scalar::one() - theta);
+                }
+                if s.dot(&y) <= tiny {
+                    return;
+                }
+            }
+        }
+        match (&self.update, &self.form) {
+            (Update::Bfgs, Form::InverseHessian) | (Update::Dfp, Form::Hessian) => {
+                Self::product_update(&mut self.metric, &s, &y)
+            }
+            (Update::Dfp, Form::InverseHessian) | (Update::Bfgs, Form::Hessian) => {
+                Self::sum_update(&mut self.metric, &s, &y)
+            }
+        }
+    }
+
+    // the hessian form solves for the direction, and fails when the metric is not positive definite
+    fn direction(&self) -> Option<VectorN<Scalar, Dimension>> {
+        match self.form {
+            Form::InverseHessian => Some(-(&self.metric * &self.g)),
+            Form::Hessian => Some(-self.metric.clone().cholesky()?.solve(&self.g)),
+        }
+    }
+
+    // the approximation of the hessian or of its inverse, depending on the form
+    pub fn metric(&self) -> MatrixN<Scalar, Dimension> {
+        self.metric.clone()
+    }
+}
+
+impl<Scalar, Dimension> DescentSearcher<Scalar, Dimension> for VariableMetric<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: DimSub<Dynamic>,
+    DefaultAllocator: Allocator<Scalar, Dimension> + Allocator<Scalar, Dimension, Dimension>,
+{
+    fn S(&self) -> VectorN<Scalar, Dimension> {
+        self.s.clone()
+    }
+    // the inverse form is inverted here, so it is only done on request
+    fn hessian(&self) -> Option<MatrixN<Scalar, Dimension>> {
+        let hessian = match self.form {
+            Form::Hessian => Some(self.metric.clone()),
+            Form::InverseHessian => self.metric.clone().try_inverse(),
+        }?;
+        Some(hessian * self.sign)
+    }
+}
+
+impl<Scalar, Dimension> extremum_searcher::Search<VectorN<Scalar, Dimension>>
+    for VariableMetric<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: DimSub<Dynamic>,
+    DefaultAllocator: Allocator<Scalar, Dimension> + Allocator<Scalar, Dimension, Dimension>,
+{
+    fn iters(&self) -> usize {
+        self.iters
+    }
+    fn error(&self) -> Option<SearchError> {
+        self.error
+    }
+    fn func_calls(&self) -> usize {
+        self.func_calls
+    }
+    fn derivative_calls(&self) -> usize {
+        self.derivative_calls
+    }
+    fn factorizations(&self) -> usize {
+        self.factorizations
+    }
+    fn x(&self) -> VectorN<Scalar, Dimension> {
+        self.x.clone()
+    }
+    fn dx(&self) -> VectorN<Scalar, Dimension> {
+        self.dx.clone()
+    }
+}
+
+impl<Scalar, Dimension> Iterator for VariableMetric<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: DimSub<Dynamic>,
+    DefaultAllocator: Allocator<Scalar, Dimension> + Allocator<Scalar, Dimension, Dimension>,
+{
+    type Item = IterationResult<VectorN<Scalar, Dimension>>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let is_extra = self.g.norm() < self.eps
+            || self.dx.iter().all(|xi| xi.abs() < self.eps)
+            || self.iters >= self.max_iters;
+        // nothing is searched once the search is over, the direction may no longer descend
+        if is_extra {
+            return Some(IterationResult::new(
+                self.x.clone(),
+                self.x.map(|_| Scalar::zero()),
+                0,
+                true,
+            ));
+        }
+        let factorizations = match self.form {
+            Form::Hessian => 1,
+            Form::InverseHessian => 0,
+        };
+        self.factorizations += factorizations;
+        self.s = match self.direction() {
+            Some(s) => s,
+            None => {
+                self.error = Some(SearchError::SingularHessian);
+                return None;
+            }
+        };
+
+        let x = self.x.clone();
+        let f = self.f.clone();
+        let df = self.df.clone();
+        let s = self.S();
+        let (_x, _s) = (x.clone(), s.clone());
+        let lambda_result = match line_searchers::search::Search::result(
+            Arc::new(move |lambda| f(x.clone() + s.clone() * lambda)),
+            Some(Arc::new(move |lambda| {
+                df(_x.clone() + _s.clone() * lambda).dot(&_s)
+            })),
+            self.comparator,
+            self.line_search.clone(),
+            Scalar::one(),
+            self.eps,
+            self.max_iters,
+        ) {
+            Ok(lambda_result) => lambda_result,
+            Err(error) => {
+                self.error = Some(error);
+                return None;
+            }
+        };
+        self.dx = self.S() * lambda_result.x();
+        self.x += self.dx.clone();
+        self.iters += 1;
+        let dg = (self.df)(self.x.clone()) * self.sign - self.g.clone();
+        self.g += dg.clone();
+
+        match self.reset {
+            Some(reset) if self.iters.is_multiple_of(reset.get()) => {
+                self.metric = Self::identity(self.x.len());
+            }
+            _ => self.update_metric(self.dx.clone(), dg),
+        }
+
+        self.func_calls += lambda_result.func_calls();
+        self.derivative_calls += lambda_result.derivative_calls() + 1;
+        Some(
+            IterationResult::new(
+                self.x.clone(),
+                self.dx.clone(),
+                lambda_result.func_calls(),
+                is_extra,
+            )
+            .with_derivative_calls(lambda_result.derivative_calls() + 1)
+            .with_factorizations(factorizations),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::searchers::descent_searchers::search::{quadratic, Method, Search};
+    use crate::searchers::one_dimension_searchers;
+    use crate::searchers::one_dimension_searchers::search::Bracketing;
+    use nalgebra::{Matrix2, Vector2, U2};
+
+    type Function = Arc<dyn Fn(Vector2<f64>) -> f64>;
+
+    fn method(update: Update, form: Form, reset: Option<NonZeroUsize>) -> Method<f64, U2> {
+        let (_, df) = quadratic();
+        let line_search = line_searchers::search::Method::Exact {
+            method: one_dimension_searchers::search::Method::Brent,
+            bracketing: Bracketing::default(),
+        };
+        match update {
+            Update::Bfgs => Method::Bfgs {
+                df,
+                line_search,
+                form,
+                guard: CurvatureGuard::Skip,
+                reset,
+            },
+            Update::Dfp => Method::Dfp {
+                df,
+                line_search,
+                form,
+                guard: CurvatureGuard::Skip,
+                reset,
+            },
+        }
+    }
+
+    #[test]
+    fn every_update_minimises_a_quadratic() {
+        for (update, form) in [
+            (Update::Bfgs, Form::InverseHessian),
+            (Update::Bfgs, Form::Hessian),
+            (Update::Dfp, Form::InverseHessian),
+            (Update::Dfp, Form::Hessian),
+        ] {
+            let (f, df) = quadratic();
+            let result = Search::Mnimimum(
+                Vector2::new(-3.0, 4.0),
+                f,
+                method(update, form, NonZeroUsize::new(10)),
+                1e-9,
+                100,
+            )
+            .unwrap();
+            assert!(df(result.x()).norm() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn only_the_hessian_form_factorises_once_per_iteration() {
+        for (form, per_iteration) in [(Form::Hessian, 1), (Form::InverseHessian, 0)] {
+            let (f, _) = quadratic();
+            let result = Search::Mnimimum(
+                Vector2::new(-3.0, 4.0),
+                f,
+                method(Update::Bfgs, form, None),
+                1e-9,
+                100,
+            )
+            .unwrap();
+            assert_eq!(result.factorizations(), per_iteration * result.iters());
+        }
+    }
+
+    #[test]
+    fn hessian_is_computed_on_request() {
+        let (f, _) = quadratic();
+        let mut search = Search::new(
+            Vector2::new(-3.0, 4.0),
+            f,
+            std::cmp::Ordering::Less,
+            method(Update::Bfgs, Form::InverseHessian, None),
+            1e-9,
+            100,
+        );
+        // with exact line searches two updates recover the hessian of a 2-D quadratic
+        search.next().unwrap();
+        search.next().unwrap();
+        let hessian = search.hessian().unwrap();
+        assert!((hessian - Matrix2::new(8.0, 1.0, 1.0, 2.0)).norm() < 1e-6);
+    }
+
+    #[test]
+    fn hessian_is_kept_after_the_result() {
+        use crate::searchers::extremum_searcher::Search as _;
+        let (f, _) = quadratic();
+        let mut search = Search::new(
+            Vector2::new(-3.0, 4.0),
+            f,
+            std::cmp::Ordering::Less,
+            method(Update::Dfp, Form::Hessian, None),
+            1e-9,
+            100,
+        );
+        search.try_result().unwrap();
+        let hessian = search.hessian().unwrap();
+        assert!((hessian - Matrix2::new(8.0, 1.0, 1.0, 2.0)).norm() < 1e-4);
+    }
+
+    #[test]
+    fn damping_keeps_the_metric_positive_definite() {
+        let f: Function = Arc::new(|x| 100.0 * (x[1] - x[0] * x[0]).powi(2) + (1.0 - x[0]).powi(2));
+        let df: Gradient<f64, U2> = Arc::new(|x| {
+            Vector2::new(
+                -400.0 * x[0] * (x[1] - x[0] * x[0]) - 2.0 * (1.0 - x[0]),
+                200.0 * (x[1] - x[0] * x[0]),
+            )
+        });
+        // backtracking alone does not keep the curvature condition
+        let mut searcher = VariableMetric::new(
+            Vector2::new(-1.2, 1.0),
+            f,
+            df,
+            std::cmp::Ordering::Less,
+            line_searchers::search::Method::Armijo { c1: 1e-4, rho: 0.5 },
+            Update::Bfgs,
+            Form::InverseHessian,
+            CurvatureGuard::Damp,
+            None,
+            1e-9,
+            1000,
+        );
+        for _ in 0..50 {
+            searcher.next().unwrap();
+            assert!(searcher.metric().cholesky().is_some());
+        }
+    }
+}
@@ -1,7 +1,103 @@
+use crate::searchers::one_dimension_searchers::search::BracketingError;
+
+// why a search stopped before converging
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SearchError {
+    Bracketing(BracketingError),
+    // the hessian could not be factorised, even after the modification
+    SingularHessian,
+    // the inexact line searches need a negative slope at zero
+    NonDescentDirection,
+    // the jacobian lost full column rank, so the gauss-newton step is undefined
+    RankDeficientJacobian,
+    // the backtracking ran out of attempts without a sufficient decrease
+    BacktrackingFailed,
+    // the root searchers that bracket need a sign change over the interval
+    NoSignChange,
+    // the named parameter is out of its range, nothing was searched
+    InvalidParameter(&'static str),
+}
+
+impl std::fmt::Display for SearchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            SearchError::Bracketing(error) => write!(f, "bracketing failed: {}", error),
+            SearchError::SingularHessian => write!(f, "singular hessian"),
+            SearchError::NonDescentDirection => write!(f, "not a descent direction"),
+            SearchError::RankDeficientJacobian => write!(f, "rank deficient jacobian"),
+            SearchError::BacktrackingFailed => write!(f, "backtracking failed"),
+            SearchError::NoSignChange => write!(f, "no sign change on the interval"),
+            SearchError::InvalidParameter(name) => write!(f, "invalid parameter: {}", name),
+        }
+    }
+}
+
+impl std::error::Error for SearchError {}
+
+impl From<BracketingError> for SearchError {
+    fn from(error: BracketingError) -> Self {
+        SearchError::Bracketing(error)
+    }
+}
+
+#[derive(Clone)]
+pub struct Interval<X> {
+    left: X,
+    right: X,
+    ratio: X,
+    x1: X,
+    x2: X,
+    f1: X,
+    f2: X,
+}
+
+impl<X> Interval<X>
+where
+    X: Clone,
+{
+    pub fn new(left: X, right: X, ratio: X, x1: X, x2: X, f1: X, f2: X) -> Self {
+        Self {
+            left,
+            right,
+            ratio,
+            x1,
+            x2,
+            f1,
+            f2,
+        }
+    }
+
+    pub fn left(&self) -> X {
+        self.left.clone()
+    }
+    pub fn right(&self) -> X {
+        self.right.clone()
+    }
+    pub fn ratio(&self) -> X {
+        self.ratio.clone()
+    }
+    pub fn x1(&self) -> X {
+        self.x1.clone()
+    }
+    pub fn x2(&self) -> X {
+        self.x2.clone()
+    }
+    pub fn f1(&self) -> X {
+        self.f1.clone()
+    }
+    pub fn f2(&self) -> X {
+        self.f2.clone()
+    }
+}
+
 pub struct IterationResult<X> {
     x: X,
     dx: X,
     func_calls: usize,
+    derivative_calls: usize,
+    hessian_calls: usize,
+    factorizations: usize,
+    interval: Option<Interval<X>>,
     is_extra: bool,
 }
 
@@ -14,9 +110,29 @@ where
             x,
             dx,
             func_calls,
+            derivative_calls: 0,
+            hessian_calls: 0,
+            factorizations: 0,
+            interval: None,
             is_extra,
         }
     }
+    pub fn with_derivative_calls(mut self, derivative_calls: usize) -> Self {
+        self.derivative_calls = derivative_calls;
+        self
+    }
+    pub fn with_hessian_calls(mut self, hessian_calls: usize) -> Self {
+        self.hessian_calls = hessian_calls;
+        self
+    }
+    pub fn with_factorizations(mut self, factorizations: usize) -> Self {
+        self.factorizations = factorizations;
+        self
+    }
+    pub fn with_interval(mut self, interval: Interval<X>) -> Self {
+        self.interval = Some(interval);
+        self
+    }
 
     pub fn x(&self) -> X {
         self.x.clone()
@@ -27,6 +143,18 @@ where
     pub fn func_calls(&self) -> usize {
         self.func_calls
     }
+    pub fn derivative_calls(&self) -> usize {
+        self.derivative_calls
+    }
+    pub fn hessian_calls(&self) -> usize {
+        self.hessian_calls
+    }
+    pub fn factorizations(&self) -> usize {
+        self.factorizations
+    }
+    pub fn interval(&self) -> Option<Interval<X>> {
+        self.interval.clone()
+    }
     pub fn is_extra(&self) -> bool {
         self.is_extra
     }
@@ -36,6 +164,9 @@ pub struct FinalResult<X> {
     x: X,
     iters: usize,
     func_calls: usize,
+    derivative_calls: usize,
+    hessian_calls: usize,
+    factorizations: usize,
 }
 
 impl<X> FinalResult<X>
@@ -47,8 +178,23 @@ where
             x,
             iters,
             func_calls,
+            derivative_calls: 0,
+            hessian_calls: 0,
+            factorizations: 0,
         }
     }
+    pub fn with_derivative_calls(mut self, derivative_calls: usize) -> Self {
+        self.derivative_calls = derivative_calls;
+        self
+    }
+    pub fn with_hessian_calls(mut self, hessian_calls: usize) -> Self {
+        self.hessian_calls = hessian_calls;
+        self
+    }
+    pub fn with_factorizations(mut self, factorizations: usize) -> Self {
+        self.factorizations = factorizations;
+        self
+    }
 
     pub fn x(&self) -> X {
         self.x.clone()
@@ -56,9 +202,18 @@ where
     pub fn func_calls(&self) -> usize {
         self.func_calls
     }
+    pub fn derivative_calls(&self) -> usize {
+        self.derivative_calls
+    }
     pub fn iters(&self) -> usize {
         self.iters
     }
+    pub fn hessian_calls(&self) -> usize {
+        self.hessian_calls
+    }
+    pub fn factorizations(&self) -> usize {
+        self.factorizations
+    }
 }
 
 pub trait Search<X: Clone>: Iterator<Item = IterationResult<X>> {
@@ -66,15 +221,44 @@ pub trait Search<X: Clone>: Iterator<Item = IterationResult<X>> {
     fn dx(&self) -> X;
     fn func_calls(&self) -> usize;
     fn iters(&self) -> usize;
+    fn derivative_calls(&self) -> usize {
+        0
+    }
+    fn hessian_calls(&self) -> usize {
+        0
+    }
+    fn factorizations(&self) -> usize {
+        0
+    }
+    // set when the iterations ended because of a failure rather than convergence
+    fn error(&self) -> Option<SearchError> {
+        None
+    }
     fn result(&mut self) -> FinalResult<X> {
         let x = self.x().clone();
         let iters = self.iters();
         let func_calls = self.func_calls();
+        let derivative_calls = self.derivative_calls();
+        let hessian_calls = self.hessian_calls();
+        let factorizations = self.factorizations();
         self.take_while(|i| !i.is_extra()).fold(
-            FinalResult::new(x, iters, func_calls),
+            FinalResult::new(x, iters, func_calls)
+                .with_derivative_calls(derivative_calls)
+                .with_hessian_calls(hessian_calls)
+                .with_factorizations(factorizations),
             |result, i| {
                 FinalResult::new(i.x(), result.iters + 1, result.func_calls + i.func_calls())
+                    .with_derivative_calls(result.derivative_calls + i.derivative_calls())
+                    .with_hessian_calls(result.hessian_calls + i.hessian_calls())
+                    .with_factorizations(result.factorizations + i.factorizations())
             },
         )
     }
+    fn try_result(&mut self) -> Result<FinalResult<X>, SearchError> {
+        let result = self.result();
+        match self.error() {
+            Some(error) => Err(error),
+            None => Ok(result),
+        }
+    }
 }
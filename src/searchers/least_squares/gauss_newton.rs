@@ -0,0 +1,198 @@
+use super::search::{covariance, LeastSquaresSearcher, Model};
+use crate::searchers::extremum_searcher;
+use crate::searchers::extremum_searcher::{IterationResult, SearchError};
+use crate::searchers::line_searchers;
+use nalgebra::{
+    allocator::Allocator, linalg::Cholesky, DVector, DefaultAllocator, DimSub, Dynamic, MatrixMN,
+    MatrixN, RealField, VectorN,
+};
+use std::sync::Arc;
+
+pub struct GaussNewton<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: DimSub<Dynamic>,
+    DefaultAllocator: Allocator<Scalar, Dimension> + Allocator<Scalar, Dimension, Dimension>,
+{
+    x: VectorN<Scalar, Dimension>,
+    dx: VectorN<Scalar, Dimension>,
+    r: DVector<Scalar>,
+    j: MatrixMN<Scalar, Dynamic, Dimension>,
+    // factorisation of `J^T J` at `x`, shared by the next step and the covariance
+    cholesky: Option<Cholesky<Scalar, Dimension>>,
+    func_calls: usize,
+    derivative_calls: usize,
+    factorizations: usize,
+    iters: usize,
+    error: Option<SearchError>,
+    model: Model<Scalar, Dimension>,
+    line_search: Option<line_searchers::search::Method<Scalar>>,
+    eps: Scalar,
+    max_iters: usize,
+}
+
+impl<Scalar, Dimension> GaussNewton<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: DimSub<Dynamic>,
+    DefaultAllocator: Allocator<Scalar, Dimension> + Allocator<Scalar, Dimension, Dimension>,
+{
+    pub fn new(
+        x0: VectorN<Scalar, Dimension>,
+        model: Model<Scalar, Dimension>,
+        line_search: Option<line_searchers::search::Method<Scalar>>,
+        eps: Scalar,
+        max_iters: usize,
+    ) -> Self {
+        let r = model.residuals(&x0);
+        let (j, func_calls, derivative_calls) = model.jacobian(&x0, &r);
+        Self {
+            dx: x0.map(|_| Scalar::max_value()),
+            cholesky: j.tr_mul(&j).cholesky(),
+            x: x0,
+            r,
+            j,
+            model,
+            line_search,
+            eps,
+            iters: 0,
+            error: None,
+            func_calls: func_calls + 1,
+            derivative_calls,
+            factorizations: 1,
+            max_iters,
+        }
+    }
+}
+
+impl<Scalar, Dimension> LeastSquaresSearcher<Scalar, Dimension> for GaussNewton<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: DimSub<Dynamic>,
+    DefaultAllocator: Allocator<Scalar, Dimension> + Allocator<Scalar, Dimension, Dimension>,
+{
+    fn J(&self) -> MatrixMN<Scalar, Dynamic, Dimension> {
+        self.j.clone()
+    }
+    fn covariance(&self) -> Option<MatrixN<Scalar, Dimension>> {
+        self.cholesky
+            .as_ref()
+            .and_then(|cholesky| covariance(cholesky, &self.r))
+    }
+    fn residual_norm(&self) -> Scalar {
+        self.r.norm()
+    }
+}
+
+impl<Scalar, Dimension> extremum_searcher::Search<VectorN<Scalar, Dimension>>
+    for GaussNewton<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: DimSub<Dynamic>,
+    DefaultAllocator: Allocator<Scalar, Dimension> + Allocator<Scalar, Dimension, Dimension>,
+{
+    fn iters(&self) -> usize {
+        self.iters
+    }
+    fn error(&self) -> Option<SearchError> {
+        self.error
+    }
+    fn func_calls(&self) -> usize {
+        self.func_calls
+    }
+    fn derivative_calls(&self) -> usize {
+        self.derivative_calls
+    }
+    fn factorizations(&self) -> usize {
+        self.factorizations
+    }
+    fn x(&self) -> VectorN<Scalar, Dimension> {
+        self.x.clone()
+    }
+    fn dx(&self) -> VectorN<Scalar, Dimension> {
+        self.dx.clone()
+    }
+}
+
+impl<Scalar, Dimension> Iterator for GaussNewton<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: DimSub<Dynamic>,
+    DefaultAllocator: Allocator<Scalar, Dimension> + Allocator<Scalar, Dimension, Dimension>,
+{
+    type Item = IterationResult<VectorN<Scalar, Dimension>>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let g = self.j.tr_mul(&self.r);
+        let is_extra = self.dx.iter().all(|xi| xi.abs() < self.eps)
+            || g.amax() < self.eps
+            || self.iters >= self.max_iters;
+        // nothing is searched once the search is over, the step may no longer descend or even be
+        // defined, `J` loses full column rank
+        if is_extra {
+            return Some(IterationResult::new(
+                self.x.clone(),
+                self.x.map(|_| Scalar::zero()),
+                0,
+                true,
+            ));
+        }
+        let p = match &self.cholesky {
+            Some(cholesky) => -cholesky.solve(&g),
+            None => {
+                self.error = Some(SearchError::RankDeficientJacobian);
+                return None;
+            }
+        };
+        let (lambda, mut func_calls) = match &self.line_search {
+            Some(line_search) => {
+                let x = self.x.clone();
+                let model = self.model.clone();
+                let s = p.clone();
+                let (_x, _model, _s) = (x.clone(), model.clone(), s.clone());
+                // the slope uses the jacobian at `x`, so it is exact at zero, where the line
+                // searches check for descent, and costs one residual call elsewhere
+                let js = &self.j * &p;
+                match line_searchers::search::Search::result(
+                    Arc::new(move |lambda| {
+                        model
+                            .residuals(&(x.clone() + s.clone() * lambda))
+                            .norm_squared()
+                    }),
+                    Some(Arc::new(move |lambda| {
+                        let r = _model.residuals(&(_x.clone() + _s.clone() * lambda));
+                        (r.dot(&js)) * Scalar::from_i8(2).unwrap()
+                    })),
+                    std::cmp::Ordering::Less,
+                    line_search.clone(),
+                    Scalar::one(),
+                    self.eps,
+                    self.max_iters,
+                ) {
+                    Ok(result) => (result.x(), result.func_calls() + result.derivative_calls()),
+                    Err(error) => {
+                        self.error = Some(error);
+                        return None;
+                    }
+                }
+            }
+            None => (Scalar::one(), 0),
+        };
+        self.dx = p * lambda;
+        self.x += self.dx.clone();
+        self.r = self.model.residuals(&self.x);
+        let (j, calls, derivative_calls) = self.model.jacobian(&self.x, &self.r);
+        self.j = j;
+        self.cholesky = self.j.tr_mul(&self.j).cholesky();
+        func_calls += calls + 1;
+        self.iters += 1;
+
+        self.func_calls += func_calls;
+        self.derivative_calls += derivative_calls;
+        self.factorizations += 1;
+        Some(
+            IterationResult::new(self.x.clone(), self.dx.clone(), func_calls, is_extra)
+                .with_derivative_calls(derivative_calls)
+                .with_factorizations(1),
+        )
+    }
+}
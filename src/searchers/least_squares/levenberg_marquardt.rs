@@ -0,0 +1,177 @@
+use super::search::{covariance, LeastSquaresSearcher, Model};
+use crate::searchers::extremum_searcher;
+use crate::searchers::extremum_searcher::IterationResult;
+use nalgebra::{
+    allocator::Allocator, DVector, DefaultAllocator, DimSub, Dynamic, MatrixMN, MatrixN, RealField,
+    VectorN,
+};
+
+pub struct LevenbergMarquardt<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: DimSub<Dynamic>,
+    DefaultAllocator: Allocator<Scalar, Dimension> + Allocator<Scalar, Dimension, Dimension>,
+{
+    x: VectorN<Scalar, Dimension>,
+    dx: VectorN<Scalar, Dimension>,
+    r: DVector<Scalar>,
+    j: MatrixMN<Scalar, Dynamic, Dimension>,
+    // damping `mu` and the factor `nu` it grows by after a rejected step
+    mu: Scalar,
+    nu: Scalar,
+    // largest coordinate of the last tried step, rejected steps leave `dx` at zero
+    step: Scalar,
+    covariance: Option<MatrixN<Scalar, Dimension>>,
+    func_calls: usize,
+    derivative_calls: usize,
+    factorizations: usize,
+    iters: usize,
+    model: Model<Scalar, Dimension>,
+    eps: Scalar,
+    max_iters: usize,
+}
+
+impl<Scalar, Dimension> LevenbergMarquardt<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: DimSub<Dynamic>,
+    DefaultAllocator: Allocator<Scalar, Dimension> + Allocator<Scalar, Dimension, Dimension>,
+{
+    pub fn new(
+        x0: VectorN<Scalar, Dimension>,
+        model: Model<Scalar, Dimension>,
+        tau: Scalar,
+        eps: Scalar,
+        max_iters: usize,
+    ) -> Self {
+        let r = model.residuals(&x0);
+        let (j, func_calls, derivative_calls) = model.jacobian(&x0, &r);
+        let a = j.tr_mul(&j);
+        let mu = tau * a.diagonal().amax();
+        let covariance = a.cholesky().and_then(|cholesky| covariance(&cholesky, &r));
+        Self {
+            dx: x0.map(|_| Scalar::max_value()),
+            x: x0,
+            r,
+            j,
+            mu,
+            nu: Scalar::from_i8(2).unwrap(),
+            step: Scalar::max_value(),
+            covariance,
+            model,
+            eps,
+            iters: 0,
+            func_calls: func_calls + 1,
+            derivative_calls,
+            factorizations: 1,
+            max_iters,
+        }
+    }
+}
+
+impl<Scalar, Dimension> LeastSquaresSearcher<Scalar, Dimension>
+    for LevenbergMarquardt<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: DimSub<Dynamic>,
+    DefaultAllocator: Allocator<Scalar, Dimension> + Allocator<Scalar, Dimension, Dimension>,
+{
+    fn J(&self) -> MatrixMN<Scalar, Dynamic, Dimension> {
+        self.j.clone()
+    }
+    fn covariance(&self) -> Option<MatrixN<Scalar, Dimension>> {
+        self.covariance.clone()
+    }
+    fn residual_norm(&self) -> Scalar {
+        self.r.norm()
+    }
+}
+
+impl<Scalar, Dimension> extremum_searcher::Search<VectorN<Scalar, Dimension>>
+    for LevenbergMarquardt<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: DimSub<Dynamic>,
+    DefaultAllocator: Allocator<Scalar, Dimension> + Allocator<Scalar, Dimension, Dimension>,
+{
+    fn iters(&self) -> usize {
+        self.iters
+    }
+    fn func_calls(&self) -> usize {
+        self.func_calls
+    }
+    fn derivative_calls(&self) -> usize {
+        self.derivative_calls
+    }
+    fn factorizations(&self) -> usize {
+        self.factorizations
+    }
+    fn x(&self) -> VectorN<Scalar, Dimension> {
+        self.x.clone()
+    }
+    fn dx(&self) -> VectorN<Scalar, Dimension> {
+        self.dx.clone()
+    }
+}
+
+impl<Scalar, Dimension> Iterator for LevenbergMarquardt<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: DimSub<Dynamic>,
+    DefaultAllocator: Allocator<Scalar, Dimension> + Allocator<Scalar, Dimension, Dimension>,
+{
+    type Item = IterationResult<VectorN<Scalar, Dimension>>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let g = self.j.tr_mul(&self.r);
+        let is_extra = g.amax() < self.eps || self.step < self.eps || self.iters >= self.max_iters;
+        let n = self.x.len();
+        let a = self.j.tr_mul(&self.j)
+            + MatrixN::<Scalar, Dimension>::identity_generic(
+                Dimension::from_usize(n),
+                Dimension::from_usize(n),
+            ) * self.mu;
+        let p = -a.cholesky()?.solve(&g);
+        let mut factorizations = 1;
+        let _x = self.x.clone() + &p;
+        let _r = self.model.residuals(&_x);
+        let mut func_calls = 1;
+        let mut derivative_calls = 0;
+
+        // gain ratio of the actual to the predicted reduction of `|r|^2 / 2`
+        let two = Scalar::from_i8(2).unwrap();
+        let predicted = p.dot(&(&p * self.mu - &g)) / two;
+        let rho = (self.r.norm_squared() - _r.norm_squared()) / two / predicted;
+        self.step = p.amax();
+        if rho > Scalar::zero() {
+            let (j, calls, _derivative_calls) = self.model.jacobian(&_x, &_r);
+            func_calls += calls;
+            derivative_calls += _derivative_calls;
+            self.covariance = j
+                .tr_mul(&j)
+                .cholesky()
+                .and_then(|cholesky| covariance(&cholesky, &_r));
+            factorizations += 1;
+            self.x = _x;
+            self.r = _r;
+            self.j = j;
+            self.dx = p;
+            let third = Scalar::one() / Scalar::from_i8(3).unwrap();
+            self.mu *= third.max(Scalar::one() - (two * rho - Scalar::one()).powi(3));
+            self.nu = two;
+        } else {
+            self.dx = p.map(|_| Scalar::zero());
+            self.mu *= self.nu;
+            self.nu *= two;
+        }
+        self.iters += 1;
+
+        self.func_calls += func_calls;
+        self.derivative_calls += derivative_calls;
+        self.factorizations += factorizations;
+        Some(
+            IterationResult::new(self.x.clone(), self.dx.clone(), func_calls, is_extra)
+                .with_derivative_calls(derivative_calls)
+                .with_factorizations(factorizations),
+        )
+    }
+}
@@ -0,0 +1,415 @@
+use crate::searchers::extremum_searcher;
+use crate::searchers::extremum_searcher::{FinalResult, IterationResult, SearchError};
+use crate::searchers::line_searchers;
+use nalgebra::{
+    allocator::Allocator, linalg::Cholesky, DVector, DefaultAllocator, Dim, DimSub, Dynamic,
+    MatrixMN, MatrixN, RealField, VectorN,
+};
+use std::sync::Arc;
+
+pub type Residuals<Scalar, Dimension> = Arc<dyn Fn(VectorN<Scalar, Dimension>) -> DVector<Scalar>>;
+pub type Jacobian<Scalar, Dimension> =
+    Arc<dyn Fn(VectorN<Scalar, Dimension>) -> MatrixMN<Scalar, Dynamic, Dimension>>;
+
+pub trait LeastSquaresSearcher<Scalar, Dimension>:
+    Iterator<Item = IterationResult<VectorN<Scalar, Dimension>>>
+    + extremum_searcher::Search<VectorN<Scalar, Dimension>>
+where
+    Scalar: RealField,
+    Dimension: Dim,
+    DefaultAllocator: Allocator<Scalar, Dimension>,
+{
+    // the jacobian of the residuals at the current point
+    #[allow(non_snake_case)]
+    fn J(&self) -> MatrixMN<Scalar, Dynamic, Dimension>;
+    // the covariance of the parameters at the current point, when it is defined
+    fn covariance(&self) -> Option<MatrixN<Scalar, Dimension>>
+    where
+        DefaultAllocator: Allocator<Scalar, Dimension, Dimension>;
+    fn residual_norm(&self) -> Scalar;
+}
+
+// the residual vector with its jacobian, which is approximated by forward differences when not given
+#[derive(Clone)]
+pub struct Model<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: Dim,
+    DefaultAllocator: Allocator<Scalar, Dimension>,
+{
+    r: Residuals<Scalar, Dimension>,
+    j: Option<Jacobian<Scalar, Dimension>>,
+}
+
+impl<Scalar, Dimension> Model<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: Dim,
+    DefaultAllocator: Allocator<Scalar, Dimension> + Allocator<Scalar, Dynamic, Dimension>,
+{
+    pub fn new(r: Residuals<Scalar, Dimension>, j: Option<Jacobian<Scalar, Dimension>>) -> Self {
+        Self { r, j }
+    }
+    pub fn residuals(&self, x: &VectorN<Scalar, Dimension>) -> DVector<Scalar> {
+        (self.r)(x.clone())
+    }
+    // the jacobian at `x` with the residual and jacobian calls spent, `r` are the residuals at `x`
+    pub fn jacobian(
+        &self,
+        x: &VectorN<Scalar, Dimension>,
+        r: &DVector<Scalar>,
+    ) -> (MatrixMN<Scalar, Dynamic, Dimension>, usize, usize) {
+        if let Some(j) = &self.j {
+            return (j(x.clone()), 0, 1);
+        }
+        let (m, n) = (r.len(), x.len());
+        let mut j = MatrixMN::<Scalar, Dynamic, Dimension>::zeros_generic(
+            Dynamic::new(m),
+            Dimension::from_usize(n),
+        );
+        for i in 0..n {
+            let h = Scalar::default_epsilon().sqrt() * x[i].abs().max(Scalar::one());
+            let mut _x = x.clone();
+            _x[i] += h;
+            j.set_column(i, &((self.residuals(&_x) - r) / h));
+        }
+        (j, n, 0)
+    }
+}
+
+// `(J^T J)^-1 sigma^2` with `sigma^2 = |r|^2 / (m - n)`, undefined without more residuals than unknowns
+pub fn covariance<Scalar, Dimension>(
+    cholesky: &Cholesky<Scalar, Dimension>,
+    r: &DVector<Scalar>,
+) -> Option<MatrixN<Scalar, Dimension>>
+where
+    Scalar: RealField,
+    Dimension: DimSub<Dynamic>,
+    DefaultAllocator: Allocator<Scalar, Dimension> + Allocator<Scalar, Dimension, Dimension>,
+{
+    let (m, n) = (r.len(), cholesky.l_dirty().nrows());
+    if m <= n {
+        return None;
+    }
+    let sigma2 = r.norm_squared() / Scalar::from_usize(m - n).unwrap();
+    Some(cholesky.inverse() * sigma2)
+}
+
+// the result of a fit, with the covariance of the parameters when it is defined and the residual
+// norm at the final point
+pub struct Fit<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: Dim,
+    DefaultAllocator: Allocator<Scalar, Dimension> + Allocator<Scalar, Dimension, Dimension>,
+{
+    result: FinalResult<VectorN<Scalar, Dimension>>,
+    covariance: Option<MatrixN<Scalar, Dimension>>,
+    residual_norm: Scalar,
+}
+
+impl<Scalar, Dimension> Fit<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: Dim,
+    DefaultAllocator: Allocator<Scalar, Dimension> + Allocator<Scalar, Dimension, Dimension>,
+{
+    pub fn result(&self) -> &FinalResult<VectorN<Scalar, Dimension>> {
+        &self.result
+    }
+    pub fn covariance(&self) -> Option<MatrixN<Scalar, Dimension>> {
+        self.covariance.clone()
+    }
+    pub fn residual_norm(&self) -> Scalar {
+        self.residual_norm
+    }
+}
+
+#[derive(Clone)]
+pub enum Method<Scalar> {
+    // the full gauss-newton step is taken without a line search
+    GaussNewton {
+        line_search: Option<line_searchers::search::Method<Scalar>>,
+    },
+    // the damping starts from `tau` times the largest diagonal element of `J^T J`
+    LevenbergMarquardt {
+        tau: Scalar,
+    },
+}
+
+pub struct Search<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: Dim,
+    DefaultAllocator: Allocator<Scalar, Dimension>,
+{
+    x: VectorN<Scalar, Dimension>,
+    dx: VectorN<Scalar, Dimension>,
+    func_calls: usize,
+    derivative_calls: usize,
+    factorizations: usize,
+    iters: usize,
+    method: Box<
+        dyn LeastSquaresSearcher<
+            Scalar,
+            Dimension,
+            Item = IterationResult<VectorN<Scalar, Dimension>>,
+        >,
+    >,
+}
+
+impl<Scalar, Dimension> Search<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: DimSub<Dynamic>,
+    DefaultAllocator: Allocator<Scalar, Dimension> + Allocator<Scalar, Dimension, Dimension>,
+{
+    pub fn new(
+        x0: VectorN<Scalar, Dimension>,
+        r: Residuals<Scalar, Dimension>,
+        j: Option<Jacobian<Scalar, Dimension>>,
+        method: Method<Scalar>,
+        eps: Scalar,
+        max_iters: usize,
+    ) -> Self {
+        let model = Model::new(r, j);
+        let m: Box<
+            dyn LeastSquaresSearcher<
+                Scalar,
+                Dimension,
+                Item = IterationResult<VectorN<Scalar, Dimension>>,
+            >,
+        > = match method {
+            Method::GaussNewton { line_search } => Box::new(super::gauss_newton::GaussNewton::new(
+                x0.clone(),
+                model,
+                line_search,
+                eps,
+                max_iters,
+            )),
+            Method::LevenbergMarquardt { tau } => {
+                Box::new(super::levenberg_marquardt::LevenbergMarquardt::new(
+                    x0.clone(),
+                    model,
+                    tau,
+                    eps,
+                    max_iters,
+                ))
+            }
+        };
+        Self {
+            dx: x0.map(|_| Scalar::max_value()),
+            x: x0,
+            func_calls: m.func_calls(),
+            derivative_calls: m.derivative_calls(),
+            factorizations: m.factorizations(),
+            iters: 0,
+            method: m,
+        }
+    }
+    pub fn result(
+        x0: VectorN<Scalar, Dimension>,
+        r: Residuals<Scalar, Dimension>,
+        j: Option<Jacobian<Scalar, Dimension>>,
+        method: Method<Scalar>,
+        eps: Scalar,
+        max_iters: usize,
+    ) -> Result<Fit<Scalar, Dimension>, SearchError> {
+        Self::new(x0, r, j, method, eps, max_iters).finish()
+    }
+    // the jacobian of the residuals at the current point
+    pub fn J(&self) -> MatrixMN<Scalar, Dynamic, Dimension> {
+        self.method.J()
+    }
+    // the covariance and the residual norm are taken from the final state, so they are there even
+    // when no step was needed
+    fn finish(mut self) -> Result<Fit<Scalar, Dimension>, SearchError> {
+        use crate::searchers::extremum_searcher::Search;
+        let result = self.try_result()?;
+        Ok(Fit {
+            result,
+            covariance: self.method.covariance(),
+            residual_norm: self.method.residual_norm(),
+        })
+    }
+}
+
+impl<Scalar, Dimension> extremum_searcher::Search<VectorN<Scalar, Dimension>>
+    for Search<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: Dim,
+    DefaultAllocator: Allocator<Scalar, Dimension>,
+{
+    fn iters(&self) -> usize {
+        self.iters
+    }
+    fn func_calls(&self) -> usize {
+        self.func_calls
+    }
+    fn derivative_calls(&self) -> usize {
+        self.derivative_calls
+    }
+    fn factorizations(&self) -> usize {
+        self.factorizations
+    }
+    fn error(&self) -> Option<SearchError> {
+        self.method.error()
+    }
+    fn x(&self) -> VectorN<Scalar, Dimension> {
+        self.x.clone()
+    }
+    fn dx(&self) -> VectorN<Scalar, Dimension> {
+        self.dx.clone()
+    }
+}
+
+impl<Scalar, Dimension> Iterator for Search<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: Dim,
+    DefaultAllocator: Allocator<Scalar, Dimension>,
+{
+    type Item = IterationResult<VectorN<Scalar, Dimension>>;
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.method.next() {
+            Some(r) => {
+                self.iters += 1;
+                self.func_calls += r.func_calls();
+                self.derivative_calls += r.derivative_calls();
+                self.factorizations += r.factorizations();
+                self.x = r.x();
+                self.dx = r.dx();
+                Some(r)
+            }
+            None => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nalgebra::{Matrix2, Vector2, U2};
+
+    // `2 exp(-t / 2)` sampled with a small deterministic perturbation
+    fn data() -> Vec<(f64, f64)> {
+        (0..20)
+            .map(|i| {
+                let t = i as f64 / 4.0;
+                (t, 2.0 * (-t / 2.0).exp() + 0.01 * (3.0 * t).sin())
+            })
+            .collect()
+    }
+
+    fn residuals() -> Residuals<f64, U2> {
+        Arc::new(|x| {
+            DVector::from_iterator(
+                20,
+                data().into_iter().map(|(t, y)| x[0] * (x[1] * t).exp() - y),
+            )
+        })
+    }
+
+    fn jacobian() -> Jacobian<f64, U2> {
+        Arc::new(|x| {
+            MatrixMN::<f64, Dynamic, U2>::from_fn(20, |i, k| {
+                let t = data()[i].0;
+                match k {
+                    0 => (x[1] * t).exp(),
+                    _ => x[0] * t * (x[1] * t).exp(),
+                }
+            })
+        })
+    }
+
+    // `(J^T J)^-1 |r|^2 / (m - n)` computed directly at `x`
+    fn reference_covariance(x: Vector2<f64>) -> Matrix2<f64> {
+        let j = jacobian()(x);
+        let r = residuals()(x);
+        (j.transpose() * j).try_inverse().unwrap() * (r.norm_squared() / 18.0)
+    }
+
+    fn methods() -> [Method<f64>; 3] {
+        [
+            Method::GaussNewton { line_search: None },
+            Method::GaussNewton {
+                line_search: Some(line_searchers::search::Method::Armijo { c1: 1e-4, rho: 0.5 }),
+            },
+            Method::LevenbergMarquardt { tau: 1e-3 },
+        ]
+    }
+
+    #[test]
+    fn every_method_fits_an_exponential_with_its_covariance() {
+        for method in methods() {
+            for j in [Some(jacobian()), None] {
+                let fit = Search::result(
+                    Vector2::new(1.0, -1.0),
+                    residuals(),
+                    j,
+                    method.clone(),
+                    1e-12,
+                    200,
+                )
+                .unwrap();
+                let x = fit.result().x();
+                assert!((x - Vector2::new(2.0, -0.5)).norm() < 1e-2);
+                let reference = reference_covariance(x);
+                assert!((fit.covariance().unwrap() - reference).norm() < 1e-3 * reference.norm());
+                let norm = residuals()(x).norm();
+                assert!((fit.residual_norm() - norm).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn jacobian_is_taken_at_the_current_point() {
+        use crate::searchers::extremum_searcher::Search as _;
+        for method in methods() {
+            for j in [Some(jacobian()), None] {
+                let mut search = Search::new(
+                    Vector2::new(1.0, -1.0),
+                    residuals(),
+                    j,
+                    method.clone(),
+                    1e-12,
+                    200,
+                );
+                search.nth(2).unwrap();
+                let reference = jacobian()(search.x());
+                assert!((search.J() - &reference).norm() < 1e-6 * reference.norm());
+            }
+        }
+    }
+
+    #[test]
+    fn diagnostics_are_reported_when_no_step_is_needed() {
+        // a line through (0, 1), (1, 3) and (2, 5), started at its exact fit
+        let r: Residuals<f64, U2> = Arc::new(|x| {
+            DVector::from_vec(vec![x[0] - 1.0, x[0] + x[1] - 3.0, x[0] + 2.0 * x[1] - 5.0])
+        });
+        for method in methods() {
+            let fit =
+                Search::result(Vector2::new(1.0, 2.0), r.clone(), None, method, 1e-8, 100).unwrap();
+            assert_eq!(fit.result().iters(), 0);
+            assert!(fit.covariance().is_some());
+            assert!(fit.residual_norm() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn rank_deficient_jacobian_is_reported() {
+        let r: Residuals<f64, U2> =
+            Arc::new(|x| DVector::from_vec(vec![x[0] - 1.0, x[0] - 2.0, x[0] + 1.0]));
+        let result = Search::result(
+            Vector2::new(0.0, 0.0),
+            r,
+            None,
+            Method::GaussNewton { line_search: None },
+            1e-8,
+            100,
+        );
+        assert_eq!(result.err(), Some(SearchError::RankDeficientJacobian));
+    }
+}
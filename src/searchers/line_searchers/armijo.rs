@@ -0,0 +1,96 @@
+use super::search::{LineSearcher, Phi};
+use crate::searchers::extremum_searcher;
+use crate::searchers::extremum_searcher::IterationResult;
+
+use nalgebra::RealField;
+
+#[derive(Clone)]
+pub struct Armijo<Scalar> {
+    phi: Phi<Scalar>,
+    phi0: Scalar,
+    dphi0: Scalar,
+    lambda: Scalar,
+    phi_lambda: Scalar,
+    c1: Scalar,
+    rho: Scalar,
+    dx: Scalar,
+    iters: usize,
+    max_iters: usize,
+}
+
+impl<Scalar> Armijo<Scalar>
+where
+    Scalar: RealField,
+{
+    pub fn new(
+        mut phi: Phi<Scalar>,
+        phi0: Scalar,
+        dphi0: Scalar,
+        lambda0: Scalar,
+        c1: Scalar,
+        rho: Scalar,
+        max_iters: usize,
+    ) -> Self {
+        let (lambda, phi_lambda) = if dphi0 < Scalar::zero() {
+            (lambda0, phi.value(lambda0))
+        } else {
+            (Scalar::zero(), phi0)
+        };
+        Self {
+            phi,
+            phi0,
+            dphi0,
+            lambda,
+            phi_lambda,
+            c1,
+            rho,
+            dx: Scalar::max_value(),
+            iters: 0,
+            max_iters,
+        }
+    }
+    fn is_sufficient_decrease(&self) -> bool {
+        self.phi_lambda <= self.phi0 + self.c1 * self.lambda * self.dphi0
+    }
+}
+
+impl<Scalar> LineSearcher<Scalar> for Armijo<Scalar> where Scalar: RealField {}
+
+impl<Scalar> extremum_searcher::Search<Scalar> for Armijo<Scalar>
+where
+    Scalar: RealField,
+{
+    fn iters(&self) -> usize {
+        self.iters
+    }
+    fn func_calls(&self) -> usize {
+        self.phi.func_calls()
+    }
+    fn derivative_calls(&self) -> usize {
+        self.phi.derivative_calls()
+    }
+    fn x(&self) -> Scalar {
+        self.lambda
+    }
+    fn dx(&self) -> Scalar {
+        self.dx
+    }
+}
+
+impl<Scalar> Iterator for Armijo<Scalar>
+where
+    Scalar: RealField,
+{
+    type Item = IterationResult<Scalar>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.is_sufficient_decrease() || self.iters >= self.max_iters {
+            return Some(IterationResult::new(self.lambda, Scalar::zero(), 0, true));
+        }
+        let _lambda = self.lambda;
+        self.lambda *= self.rho;
+        self.phi_lambda = self.phi.value(self.lambda);
+        self.dx = self.lambda - _lambda;
+        self.iters += 1;
+        Some(IterationResult::new(self.lambda, self.dx, 1, false))
+    }
+}
@@ -0,0 +1,122 @@
+use super::search::{LineSearcher, Phi};
+use crate::searchers::extremum_searcher;
+use crate::searchers::extremum_searcher::IterationResult;
+
+use nalgebra::RealField;
+
+#[derive(Clone)]
+pub struct Goldstein<Scalar> {
+    phi: Phi<Scalar>,
+    phi0: Scalar,
+    dphi0: Scalar,
+    lambda: Scalar,
+    phi_lambda: Scalar,
+    left: Scalar,
+    right: Scalar,
+    c: Scalar,
+    expansion: Scalar,
+    dx: Scalar,
+    iters: usize,
+    eps: Scalar,
+    max_iters: usize,
+}
+
+impl<Scalar> Goldstein<Scalar>
+where
+    Scalar: RealField,
+{
+    // `c` has to be in (0, 1/2)
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        mut phi: Phi<Scalar>,
+        phi0: Scalar,
+        dphi0: Scalar,
+        lambda0: Scalar,
+        c: Scalar,
+        expansion: Scalar,
+        eps: Scalar,
+        max_iters: usize,
+    ) -> Self {
+        let (lambda, phi_lambda) = if dphi0 < Scalar::zero() {
+            (lambda0, phi.value(lambda0))
+        } else {
+            (Scalar::zero(), phi0)
+        };
+        Self {
+            phi,
+            phi0,
+            dphi0,
+            lambda,
+            phi_lambda,
+            left: Scalar::zero(),
+            right: Scalar::max_value(),
+            c,
+            expansion,
+            dx: Scalar::max_value(),
+            iters: 0,
+            eps,
+            max_iters,
+        }
+    }
+    fn is_too_long(&self) -> bool {
+        self.phi_lambda > self.phi0 + self.c * self.lambda * self.dphi0
+    }
+    fn is_too_short(&self) -> bool {
+        self.phi_lambda < self.phi0 + (Scalar::one() - self.c) * self.lambda * self.dphi0
+    }
+}
+
+impl<Scalar> LineSearcher<Scalar> for Goldstein<Scalar> where Scalar: RealField {}
+
+impl<Scalar> extremum_searcher::Search<Scalar> for Goldstein<Scalar>
+where
+    Scalar: RealField,
+{
+    fn iters(&self) -> usize {
+        self.iters
+    }
+    fn func_calls(&self) -> usize {
+        self.phi.func_calls()
+    }
+    fn derivative_calls(&self) -> usize {
+        self.phi.derivative_calls()
+    }
+    fn x(&self) -> Scalar {
+        self.lambda
+    }
+    fn dx(&self) -> Scalar {
+        self.dx
+    }
+}
+
+impl<Scalar> Iterator for Goldstein<Scalar>
+where
+    Scalar: RealField,
+{
+    type Item = IterationResult<Scalar>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let is_too_long = self.is_too_long();
+        let is_too_short = self.is_too_short();
+        if !(is_too_long || is_too_short)
+            || (self.right < Scalar::max_value() && self.right - self.left < self.eps)
+            || self.iters >= self.max_iters
+        {
+            return Some(IterationResult::new(self.lambda, Scalar::zero(), 0, true));
+        }
+        let _lambda = self.lambda;
+        if is_too_long {
+            self.right = self.lambda;
+        } else {
+            self.left = self.lambda;
+        }
+        self.lambda = if self.right < Scalar::max_value() {
+            (self.left + self.right) / Scalar::from_i8(2).unwrap()
+        } else {
+            self.lambda * self.expansion
+        };
+        self.phi_lambda = self.phi.value(self.lambda);
+        self.dx = self.lambda - _lambda;
+        self.iters += 1;
+        Some(IterationResult::new(self.lambda, self.dx, 1, false))
+    }
+}
@@ -0,0 +1,330 @@
+use super::search::{LineSearcher, Phi};
+use crate::searchers::extremum_searcher;
+use crate::searchers::extremum_searcher::IterationResult;
+
+use nalgebra::RealField;
+
+// the strong wolfe search of Moré and Thuente: the interval of uncertainty is updated with
+// safeguarded cubic and quadratic steps, until a step with sufficient decrease and a non-negative
+// slope is seen the update runs on `psi(lambda) = phi(lambda) - c1 * lambda * phi'(0)`
+#[derive(Clone)]
+pub struct MoreThuente<Scalar> {
+    phi: Phi<Scalar>,
+    phi0: Scalar,
+    dphi0: Scalar,
+    c1: Scalar,
+    c2: Scalar,
+    lambda: Scalar,
+    phi_lambda: Scalar,
+    dphi_lambda: Scalar,
+    // the end of the interval with the least value and the other end, as (step, value, slope)
+    best: (Scalar, Scalar, Scalar),
+    other: (Scalar, Scalar, Scalar),
+    bracketed: bool,
+    auxiliary: bool,
+    // bounds of the next trial
+    min: Scalar,
+    max: Scalar,
+    // the last two widths of the interval, the trial bisects when it shrinks too slowly
+    width: Scalar,
+    previous_width: Scalar,
+    converged: bool,
+    done: bool,
+    dx: Scalar,
+    iters: usize,
+    eps: Scalar,
+    max_iters: usize,
+}
+
+impl<Scalar> MoreThuente<Scalar>
+where
+    Scalar: RealField,
+{
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        mut phi: Phi<Scalar>,
+        phi0: Scalar,
+        dphi0: Scalar,
+        lambda0: Scalar,
+        c1: Scalar,
+        c2: Scalar,
+        eps: Scalar,
+        max_iters: usize,
+    ) -> Self {
+        let origin = (Scalar::zero(), phi0, dphi0);
+        let done = dphi0.partial_cmp(&Scalar::zero()) != Some(std::cmp::Ordering::Less);
+        let (lambda, phi_lambda, dphi_lambda) = if done {
+            origin
+        } else {
+            (lambda0, phi.value(lambda0), phi.slope(lambda0))
+        };
+        let mut s = Self {
+            phi,
+            phi0,
+            dphi0,
+            c1,
+            c2,
+            lambda,
+            phi_lambda,
+            dphi_lambda,
+            best: origin,
+            other: origin,
+            bracketed: false,
+            auxiliary: true,
+            min: Scalar::zero(),
+            max: lambda0 * Scalar::from_i8(5).unwrap(),
+            width: Scalar::max_value(),
+            previous_width: Scalar::max_value(),
+            converged: false,
+            done,
+            dx: Scalar::max_value(),
+            iters: 0,
+            eps,
+            max_iters,
+        };
+        if !s.done {
+            s.test();
+        }
+        s
+    }
+
+    fn test(&mut self) {
+        let is_sufficient_decrease =
+            self.phi_lambda <= self.phi0 + self.c1 * self.lambda * self.dphi0;
+        if is_sufficient_decrease && self.dphi_lambda >= Scalar::zero() {
+            self.auxiliary = false;
+        }
+        if is_sufficient_decrease && self.dphi_lambda.abs() <= -self.c2 * self.dphi0 {
+            self.converged = true;
+            self.done = true;
+        }
+    }
+
+    // updates the interval with the current trial and returns the next one, or `None` once the
+    // interval is too short to hold another trial
+    fn update(&mut self) -> Option<Scalar> {
+        let two = Scalar::from_i8(2).unwrap();
+        let trial = (self.lambda, self.phi_lambda, self.dphi_lambda);
+        let is_sufficient_decrease =
+            self.phi_lambda <= self.phi0 + self.c1 * self.lambda * self.dphi0;
+        let mut lambda = if self.auxiliary && !is_sufficient_decrease && trial.1 <= self.best.1 {
+            let g = self.c1 * self.dphi0;
+            let psi = |(l, v, d): (Scalar, Scalar, Scalar)| (l, v - l * g, d - g);
+            let (mut best, mut other) = (psi(self.best), psi(self.other));
+            let lambda = step(
+                &mut best,
+                &mut other,
+                psi(trial),
+                &mut self.bracketed,
+                self.min,
+                self.max,
+            );
+            let phi = |(l, v, d): (Scalar, Scalar, Scalar)| (l, v + l * g, d + g);
+            self.best = phi(best);
+            self.other = phi(other);
+            lambda
+        } else {
+            step(
+                &mut self.best,
+                &mut self.other,
+                trial,
+                &mut self.bracketed,
+                self.min,
+                self.max,
+            )
+        };
+        let (best, other) = (self.best.0, self.other.0);
+        if self.bracketed {
+            let width = (other - best).abs();
+            if width >= Scalar::from_f64(0.66).unwrap() * self.previous_width {
+                lambda = best + (other - best) / two;
+            }
+            self.previous_width = self.width;
+            self.width = width;
+            self.min = best.min(other);
+            self.max = best.max(other);
+        } else {
+            self.min = lambda + Scalar::from_f64(1.1).unwrap() * (lambda - best);
+            self.max = lambda + Scalar::from_i8(4).unwrap() * (lambda - best);
+        }
+        let lambda = lambda.max(Scalar::zero());
+        if self.bracketed
+            && (lambda <= self.min
+                || lambda >= self.max
+                || self.max - self.min <= self.eps * self.max)
+        {
+            None
+        } else {
+            Some(lambda)
+        }
+    }
+
+    fn best(&self) -> Scalar {
+        if self.converged || self.phi_lambda < self.best.1 {
+            self.lambda
+        } else {
+            self.best.0
+        }
+    }
+}
+
+// the safeguarded step of Moré and Thuente from the interval `best`, `other` and the `trial`, all
+// as (step, value, slope): picks the next trial among the minimizers of the cubic and quadratic
+// interpolants, then replaces an end of the interval by the trial
+fn step<Scalar: RealField>(
+    best: &mut (Scalar, Scalar, Scalar),
+    other: &mut (Scalar, Scalar, Scalar),
+    trial: (Scalar, Scalar, Scalar),
+    bracketed: &mut bool,
+    min: Scalar,
+    max: Scalar,
+) -> Scalar {
+    let two = Scalar::from_i8(2).unwrap();
+    let (x, fx, dx) = *best;
+    let (y, fy, dy) = *other;
+    let (t, ft, dt) = trial;
+    let next = if ft > fx {
+        // a higher value brackets the minimum, the trial stays close to the best end
+        let (theta, gamma) = cubic((x, fx, dx), trial);
+        let gamma = if t < x { -gamma } else { gamma };
+        let r = ((gamma - dx) + theta) / (((gamma - dx) + gamma) + dt);
+        let c = x + r * (t - x);
+        let q = x + dx / ((fx - ft) / (t - x) + dx) / two * (t - x);
+        *bracketed = true;
+        if (c - x).abs() < (q - x).abs() {
+            c
+        } else {
+            c + (q - c) / two
+        }
+    } else if dt * dx < Scalar::zero() {
+        // the slopes of opposite signs bracket the minimum, the trial is the one nearer `x`
+        let (theta, gamma) = cubic((x, fx, dx), trial);
+        let gamma = if t > x { -gamma } else { gamma };
+        let r = ((gamma - dt) + theta) / (((gamma - dt) + gamma) + dx);
+        let c = t + r * (x - t);
+        let q = t + dt / (dt - dx) * (x - t);
+        *bracketed = true;
+        if (c - t).abs() > (q - t).abs() {
+            c
+        } else {
+            q
+        }
+    } else if dt.abs() < dx.abs() {
+        // the slope decreases in magnitude, the cubic may not have a minimizer in the direction
+        let (theta, gamma) = cubic((x, fx, dx), trial);
+        let gamma = if t > x { -gamma } else { gamma };
+        let r = ((gamma - dt) + theta) / ((gamma + (dx - dt)) + gamma);
+        let c = if r < Scalar::zero() && gamma != Scalar::zero() {
+            t + r * (x - t)
+        } else if t > x {
+            max
+        } else {
+            min
+        };
+        let q = t + dt / (dt - dx) * (x - t);
+        if *bracketed {
+            let next = if (c - t).abs() < (q - t).abs() { c } else { q };
+            let limit = t + Scalar::from_f64(0.66).unwrap() * (y - t);
+            if t > x {
+                next.min(limit)
+            } else {
+                next.max(limit)
+            }
+        } else {
+            let next = if (c - t).abs() > (q - t).abs() { c } else { q };
+            next.min(max).max(min)
+        }
+    } else if *bracketed {
+        // the slope doesn't decrease, the trial is the minimizer of the cubic on `t` and `y`
+        let (theta, gamma) = cubic(trial, (y, fy, dy));
+        let gamma = if t > y { -gamma } else { gamma };
+        let r = ((gamma - dt) + theta) / (((gamma - dt) + gamma) + dy);
+        t + r * (y - t)
+    } else if t > x {
+        max
+    } else {
+        min
+    };
+    if ft > fx {
+        *other = trial;
+    } else {
+        if dt * dx < Scalar::zero() {
+            *other = *best;
+        }
+        *best = trial;
+    }
+    next
+}
+
+// `theta` and `gamma` of the minimizer of the cubic interpolating the values and the slopes at
+// `a` and `b`, scaled against overflow
+fn cubic<Scalar: RealField>(
+    (a, fa, da): (Scalar, Scalar, Scalar),
+    (b, fb, db): (Scalar, Scalar, Scalar),
+) -> (Scalar, Scalar) {
+    let theta = Scalar::from_i8(3).unwrap() * (fa - fb) / (b - a) + da + db;
+    let s = theta.abs().max(da.abs()).max(db.abs());
+    let d = (theta / s) * (theta / s) - (da / s) * (db / s);
+    (theta, s * d.max(Scalar::zero()).sqrt())
+}
+
+impl<Scalar> LineSearcher<Scalar> for MoreThuente<Scalar> where Scalar: RealField {}
+
+impl<Scalar> extremum_searcher::Search<Scalar> for MoreThuente<Scalar>
+where
+    Scalar: RealField,
+{
+    fn iters(&self) -> usize {
+        self.iters
+    }
+    fn func_calls(&self) -> usize {
+        self.phi.func_calls()
+    }
+    fn derivative_calls(&self) -> usize {
+        self.phi.derivative_calls()
+    }
+    fn x(&self) -> Scalar {
+        self.best()
+    }
+    fn dx(&self) -> Scalar {
+        self.dx
+    }
+}
+
+impl<Scalar> Iterator for MoreThuente<Scalar>
+where
+    Scalar: RealField,
+{
+    type Item = IterationResult<Scalar>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done || self.iters >= self.max_iters {
+            return Some(IterationResult::new(self.best(), Scalar::zero(), 0, true));
+        }
+        let _x = self.best();
+        let func_calls = self.phi.func_calls();
+        let derivative_calls = self.phi.derivative_calls();
+        match self.update() {
+            Some(lambda) => {
+                self.lambda = lambda;
+                self.phi_lambda = self.phi.value(lambda);
+                self.dphi_lambda = self.phi.slope(lambda);
+                self.iters += 1;
+                self.test();
+            }
+            None => {
+                self.done = true;
+                return Some(IterationResult::new(self.best(), Scalar::zero(), 0, true));
+            }
+        }
+        self.dx = self.best() - _x;
+        Some(
+            IterationResult::new(
+                self.best(),
+                self.dx,
+                self.phi.func_calls() - func_calls,
+                false,
+            )
+            .with_derivative_calls(self.phi.derivative_calls() - derivative_calls),
+        )
+    }
+}
@@ -0,0 +1,490 @@
+use crate::searchers::extremum_searcher;
+use crate::searchers::extremum_searcher::{FinalResult, IterationResult, SearchError};
+use crate::searchers::one_dimension_searchers;
+use crate::searchers::one_dimension_searchers::search::Bracketing;
+
+use nalgebra::RealField;
+use std::sync::Arc;
+
+pub trait LineSearcher<Scalar>: extremum_searcher::Search<Scalar>
+where
+    Scalar: Clone,
+{
+}
+
+// the exact line search is the one dimensional search of `f(x + lambda * S)`
+impl<Scalar> LineSearcher<Scalar> for one_dimension_searchers::search::Search<Scalar> where
+    Scalar: RealField
+{
+}
+
+#[derive(Clone)]
+pub struct Phi<Scalar> {
+    f: Arc<dyn Fn(Scalar) -> Scalar>,
+    df: Option<Arc<dyn Fn(Scalar) -> Scalar>>,
+    h: Scalar,
+    sign: Scalar,
+    func_calls: usize,
+    derivative_calls: usize,
+}
+
+impl<Scalar> Phi<Scalar>
+where
+    Scalar: RealField,
+{
+    // minimizes `f(lambda)` (or `-f` for the maximum); without `df` the slope is a central
+    // difference, whose step balances the truncation and the rounding errors
+    fn new(
+        f: Arc<dyn Fn(Scalar) -> Scalar>,
+        df: Option<Arc<dyn Fn(Scalar) -> Scalar>>,
+        comparator: std::cmp::Ordering,
+    ) -> Self {
+        let sign = if comparator == std::cmp::Ordering::Less {
+            Scalar::one()
+        } else {
+            -Scalar::one()
+        };
+        Self {
+            f,
+            df,
+            h: Scalar::default_epsilon().cbrt(),
+            sign,
+            func_calls: 0,
+            derivative_calls: 0,
+        }
+    }
+    pub fn value(&mut self, lambda: Scalar) -> Scalar {
+        self.func_calls += 1;
+        self.sign * (self.f)(lambda)
+    }
+    pub fn slope(&mut self, lambda: Scalar) -> Scalar {
+        let h = self.h * (Scalar::one() + lambda.abs());
+        let df = match &self.df {
+            Some(df) => {
+                self.derivative_calls += 1;
+                df(lambda)
+            }
+            None => {
+                self.func_calls += 2;
+                ((self.f)(lambda + h) - (self.f)(lambda - h)) / (h + h)
+            }
+        };
+        self.sign * df
+    }
+    pub fn func_calls(&self) -> usize {
+        self.func_calls
+    }
+    pub fn derivative_calls(&self) -> usize {
+        self.derivative_calls
+    }
+}
+
+#[derive(Clone)]
+pub enum Method<Scalar> {
+    Exact {
+        method: one_dimension_searchers::search::Method<Scalar>,
+        bracketing: Bracketing<Scalar>,
+    },
+    // backtracks by `0 < rho < 1` until the sufficient decrease with `0 < c1 < 1` holds
+    Armijo {
+        c1: Scalar,
+        rho: Scalar,
+    },
+    // `0 < c < 1 / 2`, the step grows by `expansion > 1` until it is bracketed
+    Goldstein {
+        c: Scalar,
+        expansion: Scalar,
+    },
+    // `0 < c1 < c2 < 1`, the step is found by the search of Moré and Thuente
+    StrongWolfe {
+        c1: Scalar,
+        c2: Scalar,
+    },
+}
+
+pub struct Search<Scalar>
+where
+    Scalar: RealField,
+{
+    x: Scalar,
+    dx: Scalar,
+    func_calls: usize,
+    derivative_calls: usize,
+    iters: usize,
+    method: Box<dyn LineSearcher<Scalar>>,
+}
+
+impl<Scalar> Search<Scalar>
+where
+    Scalar: RealField,
+{
+    pub fn new(
+        f: Arc<dyn Fn(Scalar) -> Scalar>,
+        df: Option<Arc<dyn Fn(Scalar) -> Scalar>>,
+        comparator: std::cmp::Ordering,
+        method: Method<Scalar>,
+        lambda0: Scalar,
+        eps: Scalar,
+        max_iters: usize,
+    ) -> Result<Self, SearchError> {
+        Self::validate(&method)?;
+        let mut phi = Phi::new(f.clone(), df, comparator);
+        // the inexact searches only step forward, so the slope at zero has to descend strictly
+        let (phi0, dphi0) = match method {
+            Method::Exact { .. } => (Scalar::zero(), Scalar::zero()),
+            _ => {
+                let phi0 = phi.value(Scalar::zero());
+                let dphi0 = phi.slope(Scalar::zero());
+                if dphi0.partial_cmp(&Scalar::zero()) != Some(std::cmp::Ordering::Less) {
+                    return Err(SearchError::NonDescentDirection);
+                }
+                (phi0, dphi0)
+            }
+        };
+        let m: Box<dyn LineSearcher<Scalar>> = match method {
+            Method::Exact { method, bracketing } => {
+                Box::new(one_dimension_searchers::search::Search::with_bracketing(
+                    Scalar::zero(),
+                    f,
+                    comparator,
+                    method,
+                    bracketing,
+                    eps,
+                    max_iters,
+                )?)
+            }
+            Method::Armijo { c1, rho } => Box::new(super::armijo::Armijo::new(
+                phi, phi0, dphi0, lambda0, c1, rho, max_iters,
+            )),
+            Method::Goldstein { c, expansion } => Box::new(super::goldstein::Goldstein::new(
+                phi, phi0, dphi0, lambda0, c, expansion, eps, max_iters,
+            )),
+            Method::StrongWolfe { c1, c2 } => Box::new(super::more_thuente::MoreThuente::new(
+                phi, phi0, dphi0, lambda0, c1, c2, eps, max_iters,
+            )),
+        };
+        // the one dimensional searches check their parameters themselves
+        if let Some(error) = m.error() {
+            return Err(error);
+        }
+        Ok(Self {
+            x: m.x(),
+            dx: Scalar::max_value(),
+            func_calls: m.func_calls(),
+            derivative_calls: m.derivative_calls(),
+            iters: 0,
+            method: m,
+        })
+    }
+    pub fn result(
+        f: Arc<dyn Fn(Scalar) -> Scalar>,
+        df: Option<Arc<dyn Fn(Scalar) -> Scalar>>,
+        comparator: std::cmp::Ordering,
+        method: Method<Scalar>,
+        lambda0: Scalar,
+        eps: Scalar,
+        max_iters: usize,
+    ) -> Result<FinalResult<Scalar>, SearchError> {
+        use crate::searchers::extremum_searcher::Search;
+        Ok(Self::new(f, df, comparator, method, lambda0, eps, max_iters)?.result())
+    }
+    // for directions without a preferred sign, like the coordinate axes: an uphill direction is
+    // searched the other way round, the step returned is along the original direction either way,
+    // a direction flat both ways leaves the point where it is
+    pub fn either_way(
+        f: Arc<dyn Fn(Scalar) -> Scalar>,
+        df: Option<Arc<dyn Fn(Scalar) -> Scalar>>,
+        comparator: std::cmp::Ordering,
+        method: Method<Scalar>,
+        lambda0: Scalar,
+        eps: Scalar,
+        max_iters: usize,
+    ) -> Result<FinalResult<Scalar>, SearchError> {
+        match Self::result(
+            f.clone(),
+            df.clone(),
+            comparator,
+            method.clone(),
+            lambda0,
+            eps,
+            max_iters,
+        ) {
+            Err(SearchError::NonDescentDirection) => {
+                let df = df.map(|df| -> Arc<dyn Fn(Scalar) -> Scalar> {
+                    Arc::new(move |lambda| -df(-lambda))
+                });
+                match Self::result(
+                    Arc::new(move |lambda| f(-lambda)),
+                    df,
+                    comparator,
+                    method,
+                    lambda0,
+                    eps,
+                    max_iters,
+                ) {
+                    Ok(result) => {
+                        Ok(
+                            FinalResult::new(-result.x(), result.iters(), result.func_calls())
+                                .with_derivative_calls(result.derivative_calls()),
+                        )
+                    }
+                    Err(SearchError::NonDescentDirection) => {
+                        Ok(FinalResult::new(Scalar::zero(), 0, 0))
+                    }
+                    Err(error) => Err(error),
+                }
+            }
+            result => result,
+        }
+    }
+    fn validate(method: &Method<Scalar>) -> Result<(), SearchError> {
+        let (zero, one) = (Scalar::zero(), Scalar::one());
+        match method {
+            Method::Exact { .. } => Ok(()),
+            Method::Armijo { c1, .. } if !(*c1 > zero && *c1 < one) => {
+                Err(SearchError::InvalidParameter("c1"))
+            }
+            Method::Armijo { rho, .. } if !(*rho > zero && *rho < one) => {
+                Err(SearchError::InvalidParameter("rho"))
+            }
+            Method::Goldstein { c, .. }
+                if !(*c > zero && *c < one / Scalar::from_i8(2).unwrap()) =>
+            {
+                Err(SearchError::InvalidParameter("c"))
+            }
+            Method::Goldstein { expansion, .. }
+                if expansion.partial_cmp(&one) != Some(std::cmp::Ordering::Greater) =>
+            {
+                Err(SearchError::InvalidParameter("expansion"))
+            }
+            Method::StrongWolfe { c1, .. } if !(*c1 > zero && *c1 < one) => {
+                Err(SearchError::InvalidParameter("c1"))
+            }
+            Method::StrongWolfe { c1, c2 } if !(*c2 > *c1 && *c2 < one) => {
+                Err(SearchError::InvalidParameter("c2"))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl<Scalar> extremum_searcher::Search<Scalar> for Search<Scalar>
+where
+    Scalar: RealField,
+{
+    fn iters(&self) -> usize {
+        self.iters
+    }
+    fn func_calls(&self) -> usize {
+        self.func_calls
+    }
+    fn derivative_calls(&self) -> usize {
+        self.derivative_calls
+    }
+    fn x(&self) -> Scalar {
+        self.x
+    }
+    fn dx(&self) -> Scalar {
+        self.dx
+    }
+}
+
+impl<Scalar> Iterator for Search<Scalar>
+where
+    Scalar: RealField,
+{
+    type Item = IterationResult<Scalar>;
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.method.next() {
+            Some(r) => {
+                self.iters += 1;
+                self.x = r.x();
+                self.dx = r.dx();
+                self.func_calls += r.func_calls();
+                self.derivative_calls += r.derivative_calls();
+                Some(
+                    IterationResult::new(self.x, self.dx, r.func_calls(), r.is_extra())
+                        .with_derivative_calls(r.derivative_calls()),
+                )
+            }
+            None => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // the minimum of `(lambda - 3)^2 + lambda^4 / 100` is near 2.4
+    fn f() -> Arc<dyn Fn(f64) -> f64> {
+        Arc::new(|lambda| (lambda - 3.0).powi(2) + lambda.powi(4) / 100.0)
+    }
+
+    fn df() -> Arc<dyn Fn(f64) -> f64> {
+        Arc::new(|lambda| 2.0 * (lambda - 3.0) + 0.04 * lambda.powi(3))
+    }
+
+    fn step(method: Method<f64>, df: Option<Arc<dyn Fn(f64) -> f64>>) -> f64 {
+        Search::result(f(), df, std::cmp::Ordering::Less, method, 1.0, 1e-8, 100)
+            .unwrap()
+            .x()
+    }
+
+    #[test]
+    fn armijo_step_decreases_enough() {
+        let lambda = step(Method::Armijo { c1: 0.5, rho: 0.5 }, Some(df()));
+        assert!(lambda > 0.0);
+        assert!(f()(lambda) <= f()(0.0) + 0.5 * lambda * df()(0.0));
+    }
+
+    #[test]
+    fn goldstein_step_is_neither_too_long_nor_too_short() {
+        let lambda = step(
+            Method::Goldstein {
+                c: 0.25,
+                expansion: 2.0,
+            },
+            None,
+        );
+        assert!(f()(lambda) <= f()(0.0) + 0.25 * lambda * df()(0.0));
+        assert!(f()(lambda) >= f()(0.0) + 0.75 * lambda * df()(0.0));
+    }
+
+    #[test]
+    fn strong_wolfe_step_satisfies_both_conditions() {
+        for derivative in [Some(df()), None] {
+            let lambda = step(Method::StrongWolfe { c1: 1e-4, c2: 0.1 }, derivative);
+            assert!(f()(lambda) <= f()(0.0) + 1e-4 * lambda * df()(0.0));
+            assert!(df()(lambda).abs() <= -0.1 * df()(0.0) + 1e-6);
+        }
+    }
+
+    #[test]
+    fn uphill_direction_is_reported() {
+        let uphill: Arc<dyn Fn(f64) -> f64> = Arc::new(|lambda| (lambda + 3.0).powi(2));
+        let result = Search::result(
+            uphill.clone(),
+            None,
+            std::cmp::Ordering::Less,
+            Method::Armijo { c1: 1e-4, rho: 0.5 },
+            1.0,
+            1e-8,
+            100,
+        );
+        assert_eq!(result.err(), Some(SearchError::NonDescentDirection));
+        let result = Search::either_way(
+            uphill,
+            None,
+            std::cmp::Ordering::Less,
+            Method::StrongWolfe { c1: 1e-4, c2: 0.9 },
+            1.0,
+            1e-8,
+            100,
+        )
+        .unwrap();
+        assert!(result.x() < 0.0);
+    }
+
+    #[test]
+    fn flat_direction_is_not_a_descent_direction() {
+        let flat: Arc<dyn Fn(f64) -> f64> = Arc::new(|lambda| lambda * lambda);
+        let dflat: Arc<dyn Fn(f64) -> f64> = Arc::new(|lambda| 2.0 * lambda);
+        let method = Method::StrongWolfe { c1: 1e-4, c2: 0.9 };
+        let result = Search::result(
+            flat.clone(),
+            Some(dflat.clone()),
+            std::cmp::Ordering::Less,
+            method.clone(),
+            1.0,
+            1e-8,
+            100,
+        );
+        assert_eq!(result.err(), Some(SearchError::NonDescentDirection));
+        let result = Search::either_way(
+            flat,
+            Some(dflat),
+            std::cmp::Ordering::Less,
+            method,
+            1.0,
+            1e-8,
+            100,
+        )
+        .unwrap();
+        assert_eq!(result.x(), 0.0);
+    }
+
+    #[test]
+    fn either_way_keeps_the_derivative() {
+        let uphill: Arc<dyn Fn(f64) -> f64> = Arc::new(|lambda| (lambda + 3.0).powi(2));
+        let duphill: Arc<dyn Fn(f64) -> f64> = Arc::new(|lambda| 2.0 * (lambda + 3.0));
+        let result = Search::either_way(
+            uphill,
+            Some(duphill.clone()),
+            std::cmp::Ordering::Less,
+            Method::StrongWolfe { c1: 1e-4, c2: 0.1 },
+            1.0,
+            1e-8,
+            100,
+        )
+        .unwrap();
+        assert!(result.derivative_calls() > 0);
+        assert!(duphill(result.x()).abs() <= 0.1 * duphill(0.0));
+    }
+
+    #[test]
+    fn strong_wolfe_step_shrinks_a_long_first_trial() {
+        for lambda0 in [1e-3, 100.0] {
+            let result = Search::result(
+                f(),
+                Some(df()),
+                std::cmp::Ordering::Less,
+                Method::StrongWolfe { c1: 1e-4, c2: 0.1 },
+                lambda0,
+                1e-8,
+                100,
+            )
+            .unwrap();
+            let lambda = result.x();
+            assert!(f()(lambda) <= f()(0.0) + 1e-4 * lambda * df()(0.0));
+            assert!(df()(lambda).abs() <= -0.1 * df()(0.0));
+            assert!(result.iters() < 20);
+        }
+    }
+
+    #[test]
+    fn invalid_constants_are_rejected() {
+        let cases = [
+            (Method::Armijo { c1: 0.0, rho: 0.5 }, "c1"),
+            (Method::Armijo { c1: 0.5, rho: 1.0 }, "rho"),
+            (
+                Method::Goldstein {
+                    c: 0.5,
+                    expansion: 2.0,
+                },
+                "c",
+            ),
+            (
+                Method::Goldstein {
+                    c: 0.25,
+                    expansion: 1.0,
+                },
+                "expansion",
+            ),
+            (Method::StrongWolfe { c1: 0.5, c2: 0.1 }, "c2"),
+            (Method::StrongWolfe { c1: 1.0, c2: 0.1 }, "c1"),
+        ];
+        for (method, name) in cases.iter().cloned() {
+            let result =
+                Search::result(f(), None, std::cmp::Ordering::Less, method, 1.0, 1e-8, 100);
+            assert_eq!(result.err(), Some(SearchError::InvalidParameter(name)));
+        }
+    }
+
+    #[test]
+    fn finite_difference_step_does_not_depend_on_eps() {
+        let mut phi = Phi::new(f(), None, std::cmp::Ordering::Less);
+        assert!((phi.slope(0.0) - df()(0.0)).abs() < 1e-8);
+        assert!((phi.slope(2.0) - df()(2.0)).abs() < 1e-8);
+    }
+}
@@ -0,0 +1,236 @@
+use super::search::OneDimensionalSearcher;
+use crate::searchers::extremum_searcher;
+use crate::searchers::extremum_searcher::IterationResult;
+
+use nalgebra::RealField;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct Brent<Scalar> {
+    f: Arc<dyn Fn(Scalar) -> Scalar>,
+    left: Scalar,
+    right: Scalar,
+    ratio: Scalar,
+    x: Scalar,
+    w: Scalar,
+    v: Scalar,
+    fx: Scalar,
+    fw: Scalar,
+    fv: Scalar,
+    d: Scalar,
+    e: Scalar,
+    dx: Scalar,
+    iters: usize,
+    func_calls: usize,
+    eps: Scalar,
+    max_iters: usize,
+    comparator: std::cmp::Ordering,
+}
+
+impl<Scalar> Brent<Scalar>
+where
+    Scalar: RealField,
+{
+    pub fn new(
+        left: Scalar,
+        right: Scalar,
+        f: Arc<dyn Fn(Scalar) -> Scalar>,
+        comparator: std::cmp::Ordering,
+        eps: Scalar,
+        max_iters: usize,
+    ) -> Self {
+        let ratio = (Scalar::from_i8(3).unwrap() - Scalar::from_i8(5).unwrap().sqrt())
+            / Scalar::from_i8(2).unwrap();
+        let x = left + ratio * (right - left);
+        let fx = f(x);
+        Self {
+            f,
+            left,
+            right,
+            ratio,
+            x,
+            w: x,
+            v: x,
+            fx,
+            fw: fx,
+            fv: fx,
+            d: Scalar::zero(),
+            e: Scalar::zero(),
+            dx: Scalar::max_value(),
+            iters: 0,
+            func_calls: 1,
+            eps,
+            max_iters,
+            comparator,
+        }
+    }
+
+    // ties count as better, like `<=` in the classic formulation, a NaN never does
+    fn is_better(&self, f1: Scalar, f2: Scalar) -> bool {
+        f1.partial_cmp(&f2) == Some(self.comparator) || f1 == f2
+    }
+}
+
+impl<Scalar> OneDimensionalSearcher<Scalar> for Brent<Scalar> where Scalar: RealField {}
+
+impl<Scalar> extremum_searcher::Search<Scalar> for Brent<Scalar>
+where
+    Scalar: RealField,
+{
+    fn iters(&self) -> usize {
+        self.iters
+    }
+    fn func_calls(&self) -> usize {
+        self.func_calls
+    }
+    fn x(&self) -> Scalar {
+        self.x
+    }
+    fn dx(&self) -> Scalar {
+        self.dx
+    }
+}
+
+impl<Scalar> Iterator for Brent<Scalar>
+where
+    Scalar: RealField,
+{
+    type Item = IterationResult<Scalar>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let two = Scalar::from_i8(2).unwrap();
+        let tol = self.eps / two;
+        let middle = (self.left + self.right) / two;
+        let is_extra = (self.x - middle).abs() <= self.eps - (self.right - self.left) / two
+            || self.iters >= self.max_iters;
+        let _x = self.x;
+
+        let mut golden = true;
+        if self.e.abs() > tol {
+            let r = (self.x - self.w) * (self.fx - self.fv);
+            let mut q = (self.x - self.v) * (self.fx - self.fw);
+            let mut p = (self.x - self.v) * q - (self.x - self.w) * r;
+            q = two * (q - r);
+            if q > Scalar::zero() {
+                p = -p;
+            }
+            q = q.abs();
+            let e = self.e;
+            self.e = self.d;
+            if p.abs() < (q * e / two).abs()
+                && p > q * (self.left - self.x)
+                && p < q * (self.right - self.x)
+            {
+                self.d = p / q;
+                let u = self.x + self.d;
+                if u - self.left < self.eps || self.right - u < self.eps {
+                    self.d = if middle >= self.x { tol } else { -tol };
+                }
+                golden = false;
+            }
+        }
+        if golden {
+            self.e = if self.x >= middle {
+                self.left - self.x
+            } else {
+                self.right - self.x
+            };
+            self.d = self.ratio * self.e;
+        }
+
+        let u = if self.d.abs() >= tol {
+            self.x + self.d
+        } else if self.d >= Scalar::zero() {
+            self.x + tol
+        } else {
+            self.x - tol
+        };
+        let fu = (self.f)(u);
+        if self.is_better(fu, self.fx) {
+            if u >= self.x {
+                self.left = self.x;
+            } else {
+                self.right = self.x;
+            }
+            self.v = self.w;
+            self.fv = self.fw;
+            self.w = self.x;
+            self.fw = self.fx;
+            self.x = u;
+            self.fx = fu;
+        } else {
+            if u < self.x {
+                self.left = u;
+            } else {
+                self.right = u;
+            }
+            if self.is_better(fu, self.fw) || self.w == self.x {
+                self.v = self.w;
+                self.fv = self.fw;
+                self.w = u;
+                self.fw = fu;
+            } else if self.is_better(fu, self.fv) || self.v == self.x || self.v == self.w {
+                self.v = u;
+                self.fv = fu;
+            }
+        }
+
+        self.dx = self.x - _x;
+        self.iters += 1;
+        self.func_calls += 1;
+        Some(IterationResult::new(self.x, self.dx, 1, is_extra))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::search::{self, Method};
+    use crate::searchers::extremum_searcher::{FinalResult, Search as _};
+    use std::sync::Arc;
+
+    fn result(
+        f: Arc<dyn Fn(f64) -> f64>,
+        comparator: std::cmp::Ordering,
+        method: Method<f64>,
+    ) -> FinalResult<f64> {
+        search::Search::on_interval(-1.0, 4.0, f, comparator, method, 1e-8, 200).result()
+    }
+
+    #[test]
+    fn minimises_a_quadratic() {
+        let r = result(
+            Arc::new(|x| (x - 1.3).powi(2) + 2.0),
+            std::cmp::Ordering::Less,
+            Method::Brent,
+        );
+        assert!((r.x() - 1.3).abs() < 1e-6);
+    }
+
+    #[test]
+    fn maximises_with_the_greater_comparator() {
+        let r = result(
+            Arc::new(|x| -(x - 2.5).powi(2)),
+            std::cmp::Ordering::Greater,
+            Method::Brent,
+        );
+        assert!((r.x() - 2.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn takes_fewer_calls_than_golden_section_on_a_smooth_function() {
+        let f: Arc<dyn Fn(f64) -> f64> = Arc::new(|x| (x - 0.7).powi(2) * (1.0 + x * x));
+        let brent = result(f.clone(), std::cmp::Ordering::Less, Method::Brent);
+        let golden = result(f, std::cmp::Ordering::Less, Method::GoldenRatio);
+        assert!((brent.x() - golden.x()).abs() < 1e-6);
+        assert!(brent.func_calls() < golden.func_calls());
+    }
+
+    #[test]
+    fn nan_values_do_not_panic() {
+        let r = result(
+            Arc::new(|x| if x > 3.0 { f64::NAN } else { (x - 1.0).powi(2) }),
+            std::cmp::Ordering::Less,
+            Method::Brent,
+        );
+        assert!((r.x() - 1.0).abs() < 1e-6);
+    }
+}
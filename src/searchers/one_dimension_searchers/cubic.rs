@@ -0,0 +1,188 @@
+use super::search::OneDimensionalSearcher;
+use crate::searchers::extremum_searcher;
+use crate::searchers::extremum_searcher::IterationResult;
+
+use nalgebra::RealField;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct Cubic<Scalar> {
+    f: Arc<dyn Fn(Scalar) -> Scalar>,
+    df: Arc<dyn Fn(Scalar) -> Scalar>,
+    sign: Scalar,
+    left: Scalar,
+    right: Scalar,
+    f1: Scalar,
+    f2: Scalar,
+    df1: Scalar,
+    df2: Scalar,
+    x: Scalar,
+    dx: Scalar,
+    iters: usize,
+    func_calls: usize,
+    derivative_calls: usize,
+    eps: Scalar,
+    max_iters: usize,
+}
+
+impl<Scalar> Cubic<Scalar>
+where
+    Scalar: RealField,
+{
+    pub fn new(
+        left: Scalar,
+        right: Scalar,
+        f: Arc<dyn Fn(Scalar) -> Scalar>,
+        df: Arc<dyn Fn(Scalar) -> Scalar>,
+        comparator: std::cmp::Ordering,
+        eps: Scalar,
+        max_iters: usize,
+    ) -> Self {
+        // maximum is searched as the minimum of `-f`
+        let sign = if comparator == std::cmp::Ordering::Less {
+            Scalar::one()
+        } else {
+            -Scalar::one()
+        };
+        let f1 = sign * f(left);
+        let f2 = sign * f(right);
+        let df1 = sign * df(left);
+        let df2 = sign * df(right);
+        Self {
+            f,
+            df,
+            sign,
+            left,
+            right,
+            f1,
+            f2,
+            df1,
+            df2,
+            // on a given interval the extremum may be an endpoint, and the midpoint is never
+            // evaluated, so the search starts from the better of the evaluated endpoints
+            x: if f1 <= f2 { left } else { right },
+            dx: Scalar::max_value(),
+            iters: 0,
+            func_calls: 2,
+            derivative_calls: 2,
+            eps,
+            max_iters,
+        }
+    }
+}
+
+impl<Scalar> OneDimensionalSearcher<Scalar> for Cubic<Scalar> where Scalar: RealField {}
+
+impl<Scalar> extremum_searcher::Search<Scalar> for Cubic<Scalar>
+where
+    Scalar: RealField,
+{
+    fn iters(&self) -> usize {
+        self.iters
+    }
+    fn func_calls(&self) -> usize {
+        self.func_calls
+    }
+    fn derivative_calls(&self) -> usize {
+        self.derivative_calls
+    }
+    fn x(&self) -> Scalar {
+        self.x
+    }
+    fn dx(&self) -> Scalar {
+        self.dx
+    }
+}
+
+impl<Scalar> Iterator for Cubic<Scalar>
+where
+    Scalar: RealField,
+{
+    type Item = IterationResult<Scalar>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let is_extra = self.dx.abs() < self.eps
+            || (self.right - self.left).abs() < self.eps
+            || self.iters >= self.max_iters;
+        let two = Scalar::from_i8(2).unwrap();
+        let three = Scalar::from_i8(3).unwrap();
+        let z = three * (self.f1 - self.f2) / (self.right - self.left) + self.df1 + self.df2;
+        let w2 = z * z - self.df1 * self.df2;
+        let mut x = if w2 >= Scalar::zero() {
+            let w = w2.sqrt();
+            self.right
+                - (self.right - self.left) * (self.df2 + w - z) / (self.df2 - self.df1 + two * w)
+        } else {
+            (self.right + self.left) / two
+        };
+        if !(x > self.left && x < self.right) {
+            x = (self.right + self.left) / two;
+        }
+        let fx = self.sign * (self.f)(x);
+        let dfx = self.sign * (self.df)(x);
+        if dfx >= Scalar::zero() {
+            self.right = x;
+            self.f2 = fx;
+            self.df2 = dfx;
+        } else {
+            self.left = x;
+            self.f1 = fx;
+            self.df1 = dfx;
+        }
+        self.dx = x - self.x;
+        self.x = x;
+        self.iters += 1;
+        self.func_calls += 1;
+        self.derivative_calls += 1;
+        Some(IterationResult::new(self.x, self.dx, 1, is_extra).with_derivative_calls(1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::search::{self, Method};
+    use super::*;
+    use crate::searchers::extremum_searcher::Search as _;
+
+    #[test]
+    fn minimises_a_convex_function() {
+        let result = search::Search::on_interval(
+            -1.0,
+            3.0,
+            Arc::new(|x: f64| x.exp() - 2.0 * x),
+            std::cmp::Ordering::Less,
+            Method::Cubic {
+                df: Arc::new(|x: f64| x.exp() - 2.0),
+            },
+            1e-10,
+            100,
+        )
+        .result();
+        assert!((result.x() - 2f64.ln()).abs() < 1e-8);
+    }
+
+    #[test]
+    fn solves_a_cubic_in_one_step() {
+        let f: Arc<dyn Fn(f64) -> f64> = Arc::new(|x| x.powi(3) - 3.0 * x);
+        let df: Arc<dyn Fn(f64) -> f64> = Arc::new(|x| 3.0 * x.powi(2) - 3.0);
+        let mut cubic = Cubic::new(0.0, 2.0, f, df, std::cmp::Ordering::Less, 1e-10, 100);
+        assert!((cubic.next().unwrap().x() - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn starts_from_the_better_endpoint() {
+        let f: Arc<dyn Fn(f64) -> f64> = Arc::new(|x| x * x);
+        let df: Arc<dyn Fn(f64) -> f64> = Arc::new(|x| 2.0 * x);
+        let cubic = Cubic::new(
+            1.0,
+            2.0,
+            f.clone(),
+            df.clone(),
+            std::cmp::Ordering::Less,
+            1e-10,
+            100,
+        );
+        assert_eq!(cubic.x(), 1.0);
+        let cubic = Cubic::new(1.0, 2.0, f, df, std::cmp::Ordering::Greater, 1e-10, 100);
+        assert_eq!(cubic.x(), 2.0);
+    }
+}
@@ -0,0 +1,180 @@
+use super::search::OneDimensionalSearcher;
+use crate::searchers::extremum_searcher;
+use crate::searchers::extremum_searcher::{Interval, IterationResult, SearchError};
+
+use nalgebra::RealField;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct Dichotomy<Scalar> {
+    f: Arc<dyn Fn(Scalar) -> Scalar>,
+    left: Scalar,
+    right: Scalar,
+    delta: Scalar,
+    x1: Scalar,
+    x2: Scalar,
+    f1: Scalar,
+    f2: Scalar,
+    dx: Scalar,
+    iters: usize,
+    func_calls: usize,
+    error: Option<SearchError>,
+    eps: Scalar,
+    max_iters: usize,
+    comparator: std::cmp::Ordering,
+}
+
+impl<Scalar> Dichotomy<Scalar>
+where
+    Scalar: RealField,
+{
+    // `delta` has to be positive and less than `eps / 2`, otherwise the interval never gets shorter
+    // than `2 * delta`, a search with any other `delta` reports an invalid parameter
+    pub fn new(
+        left: Scalar,
+        right: Scalar,
+        f: Arc<dyn Fn(Scalar) -> Scalar>,
+        comparator: std::cmp::Ordering,
+        delta: Scalar,
+        eps: Scalar,
+        max_iters: usize,
+    ) -> Self {
+        let x = (right + left) / Scalar::from_i8(2).unwrap();
+        let error = if delta > Scalar::zero() && delta < eps / Scalar::from_i8(2).unwrap() {
+            None
+        } else {
+            Some(SearchError::InvalidParameter("delta"))
+        };
+        Self {
+            left,
+            right,
+            f,
+            delta,
+            x1: x,
+            x2: x,
+            f1: Scalar::zero(),
+            f2: Scalar::zero(),
+            dx: Scalar::max_value(),
+            iters: 0,
+            func_calls: 0,
+            error,
+            eps,
+            max_iters,
+            comparator,
+        }
+    }
+}
+
+impl<Scalar> OneDimensionalSearcher<Scalar> for Dichotomy<Scalar> where Scalar: RealField {}
+
+impl<Scalar> extremum_searcher::Search<Scalar> for Dichotomy<Scalar>
+where
+    Scalar: RealField,
+{
+    fn iters(&self) -> usize {
+        self.iters
+    }
+    fn func_calls(&self) -> usize {
+        self.func_calls
+    }
+    fn error(&self) -> Option<SearchError> {
+        self.error
+    }
+    fn x(&self) -> Scalar {
+        (self.right + self.left) / Scalar::from_i8(2).unwrap()
+    }
+    fn dx(&self) -> Scalar {
+        self.dx
+    }
+}
+
+impl<Scalar> Iterator for Dichotomy<Scalar>
+where
+    Scalar: RealField,
+{
+    type Item = IterationResult<Scalar>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.error.is_some() {
+            return None;
+        }
+        let is_extra = (self.right - self.left).abs() < self.eps || self.iters >= self.max_iters;
+        let _x = (self.right + self.left) / Scalar::from_i8(2).unwrap();
+        self.x1 = _x - self.delta;
+        self.x2 = _x + self.delta;
+        self.f1 = (self.f)(self.x1);
+        self.f2 = (self.f)(self.x2);
+        let (left, right) = (self.left, self.right);
+        if self.f1.partial_cmp(&self.f2) == Some(self.comparator) {
+            self.right = self.x2;
+        } else {
+            self.left = self.x1;
+        }
+        let x = (self.right + self.left) / Scalar::from_i8(2).unwrap();
+        self.dx = x - _x;
+        self.iters += 1;
+        self.func_calls += 2;
+        let ratio = (self.right - self.left) / (right - left);
+        Some(
+            IterationResult::new(x, x - _x, 2, is_extra).with_interval(Interval::new(
+                left, right, ratio, self.x1, self.x2, self.f1, self.f2,
+            )),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::search::{self, Method};
+    use crate::searchers::extremum_searcher::{Search as _, SearchError};
+    use crate::searchers::line_searchers;
+    use std::sync::Arc;
+
+    fn search(delta: f64) -> search::Search<f64> {
+        search::Search::on_interval(
+            -1.0,
+            4.0,
+            Arc::new(|x| (x - 1.3).powi(2) + 2.0),
+            std::cmp::Ordering::Less,
+            Method::Dichotomy { delta },
+            1e-6,
+            200,
+        )
+    }
+
+    #[test]
+    fn minimises_a_quadratic() {
+        let mut searcher = search(1e-7);
+        let r = searcher.result();
+        assert_eq!(searcher.error(), None);
+        assert!((r.x() - 1.3).abs() < 1e-6);
+    }
+
+    #[test]
+    fn rejects_a_delta_of_half_eps_or_more() {
+        for delta in [5e-7, 1e-3, 0.0] {
+            let mut searcher = search(delta);
+            assert!(searcher.next().is_none());
+            assert_eq!(
+                searcher.error(),
+                Some(SearchError::InvalidParameter("delta"))
+            );
+        }
+    }
+
+    #[test]
+    fn the_line_search_reports_the_rejected_delta() {
+        let result = line_searchers::search::Search::new(
+            Arc::new(|lambda: f64| (lambda - 1.0).powi(2)),
+            None,
+            std::cmp::Ordering::Less,
+            line_searchers::search::Method::Exact {
+                method: Method::Dichotomy { delta: 1e-3 },
+                bracketing: search::Bracketing::default(),
+            },
+            1.0,
+            1e-6,
+            100,
+        );
+        assert_eq!(result.err(), Some(SearchError::InvalidParameter("delta")));
+    }
+}
@@ -1,6 +1,6 @@
 use super::search::OneDimensionalSearcher;
 use crate::searchers::extremum_searcher;
-use crate::searchers::extremum_searcher::IterationResult;
+use crate::searchers::extremum_searcher::{Interval, IterationResult};
 
 use nalgebra::RealField;
 use std::sync::Arc;
@@ -99,13 +99,11 @@ where
         if self.fn1 == 0 {
             return None;
         }
-        let is_extra = if self.dx.abs() < self.eps || self.iters >= self.max_iters {
-            true
-        } else {
-            false
-        };
+        let is_extra = self.dx.abs() < self.eps || self.iters >= self.max_iters;
         let _x = (self.right + self.left) / Scalar::from_i8(2).unwrap();
-        if self.f1.partial_cmp(&self.f2).unwrap() == self.comparator {
+        let (left, right, x1, x2, f1, f2) =
+            (self.left, self.right, self.x1, self.x2, self.f1, self.f2);
+        if self.f1.partial_cmp(&self.f2) == Some(self.comparator) {
             self.right = self.x2;
             self.x2 = self.x1;
             self.x1 = self.left
@@ -131,6 +129,69 @@ where
         self.dx = x - _x;
         self.iters += 1;
         self.func_calls += 1;
-        Some(IterationResult::new(x, x - _x, 1, is_extra))
+        let ratio = (self.right - self.left) / (right - left);
+        Some(
+            IterationResult::new(x, x - _x, 1, is_extra)
+                .with_interval(Interval::new(left, right, ratio, x1, x2, f1, f2)),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::search::{self, Method};
+    use std::sync::Arc;
+
+    fn f(x: f64) -> f64 {
+        (x - 1.3).powi(2) + x.exp() / 10.0
+    }
+
+    #[test]
+    fn interval_methods_trace_every_iteration() {
+        for method in [
+            Method::Fibonacci,
+            Method::GoldenRatio,
+            Method::Dichotomy { delta: 1e-8 },
+        ] {
+            let intervals: Vec<_> = search::Search::on_interval(
+                -1.0,
+                4.0,
+                Arc::new(f),
+                std::cmp::Ordering::Less,
+                method,
+                1e-6,
+                200,
+            )
+            .take(15)
+            .map(|r| r.interval().unwrap())
+            .collect();
+            assert_eq!((intervals[0].left(), intervals[0].right()), (-1.0, 4.0));
+            for interval in &intervals {
+                assert!(interval.left() <= interval.x1());
+                assert!(interval.x1() <= interval.x2());
+                assert!(interval.x2() <= interval.right());
+                assert_eq!(interval.f1(), f(interval.x1()));
+                assert_eq!(interval.f2(), f(interval.x2()));
+            }
+            // the ratio is the length of the interval the iteration leaves over the one it starts with
+            for pair in intervals.windows(2) {
+                let (a, b) = (&pair[0], &pair[1]);
+                let ratio = (b.right() - b.left()) / (a.right() - a.left());
+                assert!((a.ratio() - ratio).abs() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn minimises_a_quadratic() {
+        let r = search::Search::Mnimimum(
+            0.0,
+            Arc::new(|x: f64| (x - 1.3).powi(2)),
+            Method::Fibonacci,
+            1e-6,
+            200,
+        )
+        .unwrap();
+        assert!((r.x() - 1.3).abs() < 1e-6);
     }
 }
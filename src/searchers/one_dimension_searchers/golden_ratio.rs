@@ -0,0 +1,173 @@
+use super::search::OneDimensionalSearcher;
+use crate::searchers::extremum_searcher;
+use crate::searchers::extremum_searcher::{Interval, IterationResult};
+
+use nalgebra::RealField;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct GoldenRatio<Scalar> {
+    f: Arc<dyn Fn(Scalar) -> Scalar>,
+    left: Scalar,
+    right: Scalar,
+    ratio: Scalar,
+    x1: Scalar,
+    x2: Scalar,
+    f1: Scalar,
+    f2: Scalar,
+    dx: Scalar,
+    iters: usize,
+    func_calls: usize,
+    eps: Scalar,
+    max_iters: usize,
+    comparator: std::cmp::Ordering,
+}
+
+impl<Scalar> GoldenRatio<Scalar>
+where
+    Scalar: RealField,
+{
+    pub fn new(
+        left: Scalar,
+        right: Scalar,
+        f: Arc<dyn Fn(Scalar) -> Scalar>,
+        comparator: std::cmp::Ordering,
+        eps: Scalar,
+        max_iters: usize,
+    ) -> Self {
+        let ratio =
+            (Scalar::from_i8(5).unwrap().sqrt() - Scalar::one()) / Scalar::from_i8(2).unwrap();
+        let x1 = left + (Scalar::one() - ratio) * (right - left);
+        let x2 = left + ratio * (right - left);
+        let f1 = f(x1);
+        let f2 = f(x2);
+        Self {
+            left,
+            right,
+            f,
+            ratio,
+            f1,
+            f2,
+            x1,
+            x2,
+            dx: Scalar::max_value(),
+            iters: 0,
+            func_calls: 2,
+            eps,
+            max_iters,
+            comparator,
+        }
+    }
+}
+
+impl<Scalar> OneDimensionalSearcher<Scalar> for GoldenRatio<Scalar> where Scalar: RealField {}
+
+impl<Scalar> extremum_searcher::Search<Scalar> for GoldenRatio<Scalar>
+where
+    Scalar: RealField,
+{
+    fn iters(&self) -> usize {
+        self.iters
+    }
+    fn func_calls(&self) -> usize {
+        self.func_calls
+    }
+    fn x(&self) -> Scalar {
+        (self.right + self.left) / Scalar::from_i8(2).unwrap()
+    }
+    fn dx(&self) -> Scalar {
+        self.dx
+    }
+}
+
+impl<Scalar> Iterator for GoldenRatio<Scalar>
+where
+    Scalar: RealField,
+{
+    type Item = IterationResult<Scalar>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let is_extra = (self.right - self.left).abs() < self.eps || self.iters >= self.max_iters;
+        let _x = (self.right + self.left) / Scalar::from_i8(2).unwrap();
+        let (left, right, x1, x2, f1, f2) =
+            (self.left, self.right, self.x1, self.x2, self.f1, self.f2);
+        if self.f1.partial_cmp(&self.f2) == Some(self.comparator) {
+            self.right = self.x2;
+            self.x2 = self.x1;
+            self.x1 = self.left + (Scalar::one() - self.ratio) * (self.right - self.left);
+            self.f2 = self.f1;
+            self.f1 = (self.f)(self.x1);
+        } else {
+            self.left = self.x1;
+            self.x1 = self.x2;
+            self.x2 = self.left + self.ratio * (self.right - self.left);
+            self.f1 = self.f2;
+            self.f2 = (self.f)(self.x2);
+        }
+        let x = (self.right + self.left) / Scalar::from_i8(2).unwrap();
+        self.dx = x - _x;
+        self.iters += 1;
+        self.func_calls += 1;
+        let ratio = (self.right - self.left) / (right - left);
+        Some(
+            IterationResult::new(x, x - _x, 1, is_extra)
+                .with_interval(Interval::new(left, right, ratio, x1, x2, f1, f2)),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::search::{self, Method};
+    use super::*;
+    use crate::searchers::extremum_searcher::Search as _;
+
+    fn golden_ratio(
+        f: Arc<dyn Fn(f64) -> f64>,
+        comparator: std::cmp::Ordering,
+    ) -> GoldenRatio<f64> {
+        GoldenRatio::new(-1.0, 4.0, f, comparator, 1e-6, 200)
+    }
+
+    #[test]
+    fn minimises_a_quadratic_within_eps() {
+        let r = golden_ratio(
+            Arc::new(|x| (x - 1.3).powi(2) + 2.0),
+            std::cmp::Ordering::Less,
+        )
+        .result();
+        assert!((r.x() - 1.3).abs() < 1e-6);
+    }
+
+    #[test]
+    fn maximises_with_the_greater_comparator() {
+        let r = golden_ratio(
+            Arc::new(|x| -(x - 2.5).powi(2)),
+            std::cmp::Ordering::Greater,
+        )
+        .result();
+        assert!((r.x() - 2.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn calls_the_function_once_per_iteration() {
+        let mut searcher = golden_ratio(Arc::new(|x| (x - 1.3).powi(2)), std::cmp::Ordering::Less);
+        assert_eq!(searcher.func_calls(), 2);
+        for r in searcher.by_ref().take(10) {
+            assert_eq!(r.func_calls(), 1);
+        }
+        assert_eq!(searcher.func_calls(), 12);
+    }
+
+    #[test]
+    fn is_selectable_through_method() {
+        let r = search::Search::Mnimimum(
+            0.0,
+            Arc::new(|x: f64| (x - 1.3).powi(2)),
+            Method::GoldenRatio,
+            1e-6,
+            200,
+        )
+        .unwrap();
+        assert!((r.x() - 1.3).abs() < 1e-6);
+    }
+}
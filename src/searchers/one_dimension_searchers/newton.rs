@@ -0,0 +1,160 @@
+use super::search::OneDimensionalSearcher;
+use crate::searchers::extremum_searcher;
+use crate::searchers::extremum_searcher::IterationResult;
+
+use nalgebra::RealField;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct Newton<Scalar> {
+    df: Arc<dyn Fn(Scalar) -> Scalar>,
+    ddf: Arc<dyn Fn(Scalar) -> Scalar>,
+    left: Scalar,
+    right: Scalar,
+    x: Scalar,
+    dx: Scalar,
+    iters: usize,
+    func_calls: usize,
+    derivative_calls: usize,
+    hessian_calls: usize,
+    eps: Scalar,
+    max_iters: usize,
+    comparator: std::cmp::Ordering,
+}
+
+impl<Scalar> Newton<Scalar>
+where
+    Scalar: RealField,
+{
+    pub fn new(
+        left: Scalar,
+        right: Scalar,
+        df: Arc<dyn Fn(Scalar) -> Scalar>,
+        ddf: Arc<dyn Fn(Scalar) -> Scalar>,
+        comparator: std::cmp::Ordering,
+        eps: Scalar,
+        max_iters: usize,
+    ) -> Self {
+        Self {
+            df,
+            ddf,
+            left,
+            right,
+            x: (right + left) / Scalar::from_i8(2).unwrap(),
+            dx: Scalar::max_value(),
+            iters: 0,
+            func_calls: 0,
+            derivative_calls: 0,
+            hessian_calls: 0,
+            eps,
+            max_iters,
+            comparator,
+        }
+    }
+}
+
+impl<Scalar> OneDimensionalSearcher<Scalar> for Newton<Scalar> where Scalar: RealField {}
+
+impl<Scalar> extremum_searcher::Search<Scalar> for Newton<Scalar>
+where
+    Scalar: RealField,
+{
+    fn iters(&self) -> usize {
+        self.iters
+    }
+    fn func_calls(&self) -> usize {
+        self.func_calls
+    }
+    fn derivative_calls(&self) -> usize {
+        self.derivative_calls
+    }
+    fn hessian_calls(&self) -> usize {
+        self.hessian_calls
+    }
+    fn x(&self) -> Scalar {
+        self.x
+    }
+    fn dx(&self) -> Scalar {
+        self.dx
+    }
+}
+
+impl<Scalar> Iterator for Newton<Scalar>
+where
+    Scalar: RealField,
+{
+    type Item = IterationResult<Scalar>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let is_extra = self.dx.abs() < self.eps || self.iters >= self.max_iters;
+        let df = (self.df)(self.x);
+        let ddf = (self.ddf)(self.x);
+        // the curvature is taken by absolute value so that the step always goes towards
+        // the requested extremum, not just to the nearest stationary point
+        let direction = if self.comparator == std::cmp::Ordering::Less {
+            -df
+        } else {
+            df
+        };
+        let x = if ddf.is_zero() {
+            self.x + direction
+        } else {
+            self.x + direction / ddf.abs()
+        };
+        let x = x.max(self.left).min(self.right);
+        self.dx = x - self.x;
+        self.x = x;
+        self.iters += 1;
+        self.derivative_calls += 1;
+        self.hessian_calls += 1;
+        Some(
+            IterationResult::new(self.x, self.dx, 0, is_extra)
+                .with_derivative_calls(1)
+                .with_hessian_calls(1),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::search::{self, Method};
+    use super::*;
+    use crate::searchers::extremum_searcher::Search as _;
+
+    #[test]
+    fn minimises_a_convex_function() {
+        let result = search::Search::on_interval(
+            -1.0,
+            3.0,
+            Arc::new(|x: f64| x.exp() - 2.0 * x),
+            std::cmp::Ordering::Less,
+            Method::Newton {
+                df: Arc::new(|x: f64| x.exp() - 2.0),
+                ddf: Arc::new(f64::exp),
+            },
+            1e-10,
+            100,
+        )
+        .result();
+        assert!((result.x() - 2f64.ln()).abs() < 1e-8);
+        assert_eq!(result.derivative_calls(), result.iters());
+        assert_eq!(result.hessian_calls(), result.iters());
+    }
+
+    #[test]
+    fn maximises_a_concave_function() {
+        let result = search::Search::on_interval(
+            0.5,
+            2.5,
+            Arc::new(f64::sin),
+            std::cmp::Ordering::Greater,
+            Method::Newton {
+                df: Arc::new(f64::cos),
+                ddf: Arc::new(|x: f64| -x.sin()),
+            },
+            1e-10,
+            100,
+        )
+        .result();
+        assert!((result.x() - std::f64::consts::FRAC_PI_2).abs() < 1e-8);
+    }
+}
@@ -0,0 +1,206 @@
+use super::search::{Lipschitz, OneDimensionalSearcher};
+use crate::searchers::extremum_searcher;
+use crate::searchers::extremum_searcher::IterationResult;
+
+use nalgebra::RealField;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct Piyavskii<Scalar> {
+    f: Arc<dyn Fn(Scalar) -> Scalar>,
+    sign: Scalar,
+    lipschitz: Lipschitz<Scalar>,
+    points: Vec<(Scalar, Scalar)>,
+    best: (Scalar, Scalar),
+    gap: Scalar,
+    dx: Scalar,
+    iters: usize,
+    func_calls: usize,
+    eps: Scalar,
+    max_iters: usize,
+}
+
+impl<Scalar> Piyavskii<Scalar>
+where
+    Scalar: RealField,
+{
+    pub fn new(
+        left: Scalar,
+        right: Scalar,
+        f: Arc<dyn Fn(Scalar) -> Scalar>,
+        comparator: std::cmp::Ordering,
+        lipschitz: Lipschitz<Scalar>,
+        eps: Scalar,
+        max_iters: usize,
+    ) -> Self {
+        // maximum is searched as the minimum of `-f`
+        let sign = if comparator == std::cmp::Ordering::Less {
+            Scalar::one()
+        } else {
+            -Scalar::one()
+        };
+        let f1 = sign * f(left);
+        let f2 = sign * f(right);
+        let best = if f1 <= f2 { (left, f1) } else { (right, f2) };
+        let mut s = Self {
+            f,
+            sign,
+            lipschitz,
+            points: vec![(left, f1), (right, f2)],
+            best,
+            gap: Scalar::max_value(),
+            dx: Scalar::max_value(),
+            iters: 0,
+            func_calls: 2,
+            eps,
+            max_iters,
+        };
+        s.gap = s.best.1 - s.lower_bound().1;
+        s
+    }
+
+    fn constant(&self) -> Scalar {
+        match self.lipschitz {
+            Lipschitz::Known(l) => l,
+            Lipschitz::Adaptive { reliability } => {
+                reliability * largest_slope(&self.points).max(self.eps)
+            }
+        }
+    }
+
+    // minimum of the saw-tooth lower bound: the point and the bound value
+    fn lower_bound(&self) -> (Scalar, Scalar) {
+        let two = Scalar::from_i8(2).unwrap();
+        let l = self.constant();
+        self.points
+            .windows(2)
+            .map(|p| {
+                let ((x1, f1), (x2, f2)) = (p[0], p[1]);
+                let x = (x1 + x2) / two + (f1 - f2) / (two * l);
+                (x.max(x1).min(x2), (f1 + f2) / two - l * (x2 - x1) / two)
+            })
+            .fold((self.best.0, Scalar::max_value()), |min, p| {
+                if p.1 < min.1 {
+                    p
+                } else {
+                    min
+                }
+            })
+    }
+}
+
+// the largest absolute slope between neighbouring samples, sorted by the point; the lower bound
+// can fall on a point already sampled, such a pair has no slope
+fn largest_slope<Scalar>(points: &[(Scalar, Scalar)]) -> Scalar
+where
+    Scalar: RealField,
+{
+    points
+        .windows(2)
+        .filter(|p| p[1].0 > p[0].0)
+        .map(|p| ((p[1].1 - p[0].1) / (p[1].0 - p[0].0)).abs())
+        .fold(Scalar::zero(), |max, slope| max.max(slope))
+}
+
+impl<Scalar> OneDimensionalSearcher<Scalar> for Piyavskii<Scalar>
+where
+    Scalar: RealField,
+{
+    fn gap(&self) -> Option<Scalar> {
+        Some(self.gap)
+    }
+}
+
+impl<Scalar> extremum_searcher::Search<Scalar> for Piyavskii<Scalar>
+where
+    Scalar: RealField,
+{
+    fn iters(&self) -> usize {
+        self.iters
+    }
+    fn func_calls(&self) -> usize {
+        self.func_calls
+    }
+    fn x(&self) -> Scalar {
+        self.best.0
+    }
+    fn dx(&self) -> Scalar {
+        self.dx
+    }
+}
+
+impl<Scalar> Iterator for Piyavskii<Scalar>
+where
+    Scalar: RealField,
+{
+    type Item = IterationResult<Scalar>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let is_extra = self.gap < self.eps || self.iters >= self.max_iters;
+        let _x = self.best.0;
+        let (x, _) = self.lower_bound();
+        let fx = self.sign * (self.f)(x);
+        let i = self
+            .points
+            .iter()
+            .position(|p| p.0 > x)
+            .unwrap_or(self.points.len());
+        self.points.insert(i, (x, fx));
+        if fx < self.best.1 {
+            self.best = (x, fx);
+        }
+        // with the adaptive constant the gap is an estimate, not a guarantee
+        self.gap = self.best.1 - self.lower_bound().1;
+        self.dx = self.best.0 - _x;
+        self.iters += 1;
+        self.func_calls += 1;
+        Some(IterationResult::new(self.best.0, self.dx, 1, is_extra))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::searchers::extremum_searcher::Search as _;
+
+    fn piyavskii(lipschitz: Lipschitz<f64>, comparator: std::cmp::Ordering) -> Piyavskii<f64> {
+        // several local minima on the interval, the global one is near 5.15
+        Piyavskii::new(
+            0.0,
+            6.0,
+            Arc::new(|x: f64| x.sin() + (10.0 * x / 3.0).sin()),
+            comparator,
+            lipschitz,
+            1e-6,
+            500,
+        )
+    }
+
+    #[test]
+    fn finds_the_global_minimum() {
+        for lipschitz in [
+            Lipschitz::Known(4.5),
+            Lipschitz::Adaptive { reliability: 1.5 },
+        ] {
+            let mut searcher = piyavskii(lipschitz, std::cmp::Ordering::Less);
+            let r = searcher.result();
+            assert!((r.x() - 5.1457).abs() < 1e-3);
+            assert!(searcher.gap().unwrap() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn finds_the_global_maximum() {
+        let r = piyavskii(Lipschitz::Known(4.5), std::cmp::Ordering::Greater).result();
+        assert!((r.x() - 2.2961).abs() < 1e-3);
+    }
+
+    #[test]
+    fn largest_slope_skips_points_sampled_twice() {
+        let points = [(0.0, 0.0), (1.0, 2.0), (2.0, 1.0), (3.0, 4.0f64)];
+        // a noisy function can return another value at a point sampled before
+        let mut twice = points.to_vec();
+        twice.insert(2, (1.0, 2.0 + 1e-12));
+        assert_eq!(largest_slope(&points), 3.0);
+        assert!((largest_slope(&twice) - 3.0).abs() < 1e-9);
+    }
+}
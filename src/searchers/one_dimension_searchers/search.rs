@@ -1,56 +1,201 @@
 use crate::searchers::extremum_searcher;
-use crate::searchers::extremum_searcher::{FinalResult, IterationResult};
+use crate::searchers::extremum_searcher::{FinalResult, IterationResult, SearchError};
 
 pub trait OneDimensionalSearcher<Scalar>: extremum_searcher::Search<Scalar>
 where
     Scalar: Clone,
 {
+    // the difference between the best value and a lower bound of the function, for the global
+    // searchers that certify one
+    fn gap(&self) -> Option<Scalar> {
+        None
+    }
 }
 
 use nalgebra::RealField;
 use std::sync::Arc;
 
-fn get_interval<Scalar>(
-    f: Arc<dyn Fn(Scalar) -> Scalar>,
-    comparator: std::cmp::Ordering,
-    x0: Scalar,
-    delta: Scalar,
-) -> (Scalar, Scalar, usize)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BracketingError {
+    UnboundedDirection,
+    NonFiniteValue,
+    // the named parameter of the bracketing is out of its range, nothing was evaluated
+    InvalidParameter(&'static str),
+}
+
+impl std::fmt::Display for BracketingError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            BracketingError::UnboundedDirection => write!(f, "unbounded direction"),
+            BracketingError::NonFiniteValue => write!(f, "non-finite value"),
+            BracketingError::InvalidParameter(name) => write!(f, "invalid parameter: {}", name),
+        }
+    }
+}
+
+impl std::error::Error for BracketingError {}
+
+// the bracket is searched from `x0` with steps of `step` growing by `growth`, giving up after `max_expansions`
+#[derive(Clone)]
+pub struct Bracketing<Scalar> {
+    step: Scalar,
+    growth: Scalar,
+    max_expansions: usize,
+    bounds: Option<(Scalar, Scalar)>,
+}
+
+impl<Scalar> Default for Bracketing<Scalar>
+where
+    Scalar: RealField,
+{
+    // a step of 1/10 doubling up to 64 times covers lengths from 0.1 to about 1e18
+    fn default() -> Self {
+        Self::new(
+            Scalar::one() / Scalar::from_i8(10).unwrap(),
+            Scalar::from_i8(2).unwrap(),
+            64,
+            None,
+        )
+    }
+}
+
+impl<Scalar> Bracketing<Scalar>
 where
     Scalar: RealField,
 {
-    let mut h;
-    let mut x = x0;
-    let mut func_calls = 2;
-    let mut f1 = f(x);
-    let mut f2 = f(x + delta);
-
-    if f2.partial_cmp(&f1).unwrap() == comparator {
-        h = delta;
-    } else {
-        func_calls += 1;
-        f2 = f(x - delta);
-        if f2.partial_cmp(&f1).unwrap() == comparator {
-            h = -delta;
-        } else {
-            return (x - delta, x + delta, 3);
+    pub fn new(
+        step: Scalar,
+        growth: Scalar,
+        max_expansions: usize,
+        bounds: Option<(Scalar, Scalar)>,
+    ) -> Self {
+        Self {
+            step,
+            growth,
+            max_expansions,
+            bounds,
+        }
+    }
+
+    // a step that is not positive gives an empty bracket, a growth of at most one never expands it
+    fn validate(&self) -> Result<(), BracketingError> {
+        let is_greater = |x: Scalar, bound: Scalar| {
+            x.is_finite() && x.partial_cmp(&bound) == Some(std::cmp::Ordering::Greater)
+        };
+        if !is_greater(self.step, Scalar::zero()) {
+            return Err(BracketingError::InvalidParameter("step"));
+        }
+        if !is_greater(self.growth, Scalar::one()) {
+            return Err(BracketingError::InvalidParameter("growth"));
+        }
+        if self.max_expansions == 0 {
+            return Err(BracketingError::InvalidParameter("max_expansions"));
+        }
+        match self.bounds {
+            Some((left, right))
+                if matches!(
+                    left.partial_cmp(&right),
+                    None | Some(std::cmp::Ordering::Greater)
+                ) =>
+            {
+                Err(BracketingError::InvalidParameter("bounds"))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn clamp(&self, x: Scalar) -> Scalar {
+        match self.bounds {
+            Some((left, right)) => x.max(left).min(right),
+            None => x,
+        }
+    }
+
+    pub fn bracket(
+        &self,
+        f: Arc<dyn Fn(Scalar) -> Scalar>,
+        comparator: std::cmp::Ordering,
+        x0: Scalar,
+    ) -> Result<(Scalar, Scalar, usize), BracketingError> {
+        self.validate()?;
+        let value = |x: Scalar| {
+            let y = f(x);
+            if y.is_finite() {
+                Ok(y)
+            } else {
+                Err(BracketingError::NonFiniteValue)
+            }
+        };
+        let is_better = |f1: Scalar, f2: Scalar| f1.partial_cmp(&f2) == Some(comparator);
+
+        let x0 = self.clamp(x0);
+        let mut h = self.step;
+        let mut func_calls = 2;
+        let f0 = value(x0)?;
+        let mut x = self.clamp(x0 + h);
+        let mut fx = value(x)?;
+        if !is_better(fx, f0) {
+            func_calls += 1;
+            h = -h;
+            let _x = x;
+            x = self.clamp(x0 + h);
+            fx = value(x)?;
+            if !is_better(fx, f0) {
+                return Ok((x.min(_x), x.max(_x), func_calls));
+            }
+        }
+
+        let mut _x = x0;
+        let mut expansions = 0;
+        loop {
+            h *= self.growth;
+            let next = self.clamp(x + h);
+            if next == x {
+                return Ok((_x.min(x), _x.max(x), func_calls));
+            }
+            let f_next = value(next)?;
+            func_calls += 1;
+            if !is_better(f_next, fx) {
+                return Ok((_x.min(next), _x.max(next), func_calls));
+            }
+            expansions += 1;
+            if expansions >= self.max_expansions {
+                return Err(BracketingError::UnboundedDirection);
+            }
+            _x = x;
+            x = next;
+            fx = f_next;
         }
     }
-    x = x + h;
-    while f2.partial_cmp(&f1).unwrap() == comparator {
-        h = h * Scalar::from_i8(2).unwrap();
-        x = x + h;
-        f1 = f2;
-        f2 = f(x);
-        func_calls += 1;
-    }
-    let left = (x - Scalar::from_f64(3. / 2.).unwrap() * h).min(x);
-    let right = (x - Scalar::from_f64(3. / 2.).unwrap() * h).max(x);
-    (left, right, func_calls)
 }
 
-pub enum Method {
+#[derive(Clone)]
+pub enum Lipschitz<Scalar> {
+    Known(Scalar),
+    Adaptive { reliability: Scalar },
+}
+
+#[derive(Clone)]
+pub enum Method<Scalar> {
     Fibonacci,
+    GoldenRatio,
+    Dichotomy {
+        delta: Scalar,
+    },
+    Brent,
+    Newton {
+        df: Arc<dyn Fn(Scalar) -> Scalar>,
+        ddf: Arc<dyn Fn(Scalar) -> Scalar>,
+    },
+    Secant {
+        df: Arc<dyn Fn(Scalar) -> Scalar>,
+    },
+    Cubic {
+        df: Arc<dyn Fn(Scalar) -> Scalar>,
+    },
+    Piyavskii {
+        lipschitz: Lipschitz<Scalar>,
+    },
 }
 
 pub struct Search<Scalar>
@@ -60,8 +205,10 @@ where
     x: Scalar,
     dx: Scalar,
     func_calls: usize,
+    derivative_calls: usize,
+    hessian_calls: usize,
     iters: usize,
-    method: Box<dyn extremum_searcher::Search<Scalar>>,
+    method: Box<dyn OneDimensionalSearcher<Scalar>>,
 }
 
 impl<Scalar> Search<Scalar>
@@ -72,55 +219,112 @@ where
         x0: Scalar,
         f: Arc<dyn Fn(Scalar) -> Scalar>,
         comparator: std::cmp::Ordering,
-        method: Method,
+        method: Method<Scalar>,
+        eps: Scalar,
+        max_iters: usize,
+    ) -> Result<Self, BracketingError> {
+        Self::with_bracketing(
+            x0,
+            f,
+            comparator,
+            method,
+            Bracketing::default(),
+            eps,
+            max_iters,
+        )
+    }
+    pub fn with_bracketing(
+        x0: Scalar,
+        f: Arc<dyn Fn(Scalar) -> Scalar>,
+        comparator: std::cmp::Ordering,
+        method: Method<Scalar>,
+        bracketing: Bracketing<Scalar>,
+        eps: Scalar,
+        max_iters: usize,
+    ) -> Result<Self, BracketingError> {
+        let (left, right, func_calls) = bracketing.bracket(f.clone(), comparator, x0)?;
+        let mut search = Self::on_interval(left, right, f, comparator, method, eps, max_iters);
+        search.func_calls += func_calls;
+        Ok(search)
+    }
+    pub fn on_interval(
+        left: Scalar,
+        right: Scalar,
+        f: Arc<dyn Fn(Scalar) -> Scalar>,
+        comparator: std::cmp::Ordering,
+        method: Method<Scalar>,
         eps: Scalar,
         max_iters: usize,
     ) -> Self {
-        let (left, right, func_calls) = get_interval(f.clone(), comparator, x0, eps);
-        let m = match method {
-            Method::Fibonacci => {
-                super::fibonacci::Fibonacci::new(left, right, f, comparator, eps, max_iters)
-            }
+        let m: Box<dyn OneDimensionalSearcher<Scalar>> = match method {
+            Method::Fibonacci => Box::new(super::fibonacci::Fibonacci::new(
+                left, right, f, comparator, eps, max_iters,
+            )),
+            Method::GoldenRatio => Box::new(super::golden_ratio::GoldenRatio::new(
+                left, right, f, comparator, eps, max_iters,
+            )),
+            Method::Dichotomy { delta } => Box::new(super::dichotomy::Dichotomy::new(
+                left, right, f, comparator, delta, eps, max_iters,
+            )),
+            Method::Brent => Box::new(super::brent::Brent::new(
+                left, right, f, comparator, eps, max_iters,
+            )),
+            Method::Newton { df, ddf } => Box::new(super::newton::Newton::new(
+                left, right, df, ddf, comparator, eps, max_iters,
+            )),
+            Method::Secant { df } => Box::new(super::secant::Secant::new(
+                left, right, df, comparator, eps, max_iters,
+            )),
+            Method::Cubic { df } => Box::new(super::cubic::Cubic::new(
+                left, right, f, df, comparator, eps, max_iters,
+            )),
+            Method::Piyavskii { lipschitz } => Box::new(super::piyavskii::Piyavskii::new(
+                left, right, f, comparator, lipschitz, eps, max_iters,
+            )),
         };
-        use crate::searchers::extremum_searcher::Search;
         Self {
             x: (right + left) / Scalar::from_i8(2).unwrap(),
             dx: Scalar::max_value(),
-            func_calls: func_calls + m.func_calls(),
+            func_calls: m.func_calls(),
+            derivative_calls: m.derivative_calls(),
+            hessian_calls: m.hessian_calls(),
             iters: 0,
-            method: Box::new(m),
+            method: m,
         }
     }
     pub fn result(
         x0: Scalar,
         f: Arc<dyn Fn(Scalar) -> Scalar>,
         comparator: std::cmp::Ordering,
-        method: Method,
+        method: Method<Scalar>,
         eps: Scalar,
         max_iters: usize,
-    ) -> FinalResult<Scalar> {
+    ) -> Result<FinalResult<Scalar>, BracketingError> {
         use crate::searchers::extremum_searcher::Search;
-        Self::new(x0, f, comparator, method, eps, max_iters).result()
+        Ok(Self::new(x0, f, comparator, method, eps, max_iters)?.result())
     }
     pub fn Mnimimum(
         x0: Scalar,
         f: Arc<dyn Fn(Scalar) -> Scalar>,
-        method: Method,
+        method: Method<Scalar>,
         eps: Scalar,
         max_iters: usize,
-    ) -> FinalResult<Scalar> {
+    ) -> Result<FinalResult<Scalar>, BracketingError> {
         use crate::searchers::extremum_searcher::Search;
-        Self::new(x0, f, std::cmp::Ordering::Less, method, eps, max_iters).result()
+        Ok(Self::new(x0, f, std::cmp::Ordering::Less, method, eps, max_iters)?.result())
     }
     pub fn Maximum(
         x0: Scalar,
         f: Arc<dyn Fn(Scalar) -> Scalar>,
-        method: Method,
+        method: Method<Scalar>,
         eps: Scalar,
         max_iters: usize,
-    ) -> FinalResult<Scalar> {
+    ) -> Result<FinalResult<Scalar>, BracketingError> {
         use crate::searchers::extremum_searcher::Search;
-        Self::new(x0, f, std::cmp::Ordering::Greater, method, eps, max_iters).result()
+        Ok(Self::new(x0, f, std::cmp::Ordering::Greater, method, eps, max_iters)?.result())
+    }
+    pub fn gap(&self) -> Option<Scalar> {
+        self.method.gap()
     }
 }
 
@@ -134,6 +338,15 @@ where
     fn func_calls(&self) -> usize {
         self.func_calls
     }
+    fn derivative_calls(&self) -> usize {
+        self.derivative_calls
+    }
+    fn hessian_calls(&self) -> usize {
+        self.hessian_calls
+    }
+    fn error(&self) -> Option<SearchError> {
+        self.method.error()
+    }
     fn x(&self) -> Scalar {
         self.x
     }
@@ -154,14 +367,172 @@ where
                 self.x = r.x();
                 self.dx = r.dx();
                 self.func_calls += r.func_calls();
-                Some(IterationResult::new(
-                    self.x,
-                    self.dx,
-                    r.func_calls(),
-                    r.is_extra(),
-                ))
+                self.derivative_calls += r.derivative_calls();
+                self.hessian_calls += r.hessian_calls();
+                Some(r)
             }
             None => None,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bracket_contains_the_minimum() {
+        let f: Arc<dyn Fn(f64) -> f64> = Arc::new(|x| (x - 3.0).powi(2));
+        let (left, right, _) = Bracketing::default()
+            .bracket(f, std::cmp::Ordering::Less, 0.0)
+            .unwrap();
+        assert!(left < 3.0 && 3.0 < right);
+    }
+
+    #[test]
+    fn bracket_reports_an_unbounded_direction() {
+        let f: Arc<dyn Fn(f64) -> f64> = Arc::new(|x| -x);
+        let result = Bracketing::new(0.1, 2.0, 10, None).bracket(f, std::cmp::Ordering::Less, 0.0);
+        assert_eq!(result, Err(BracketingError::UnboundedDirection));
+    }
+
+    #[test]
+    fn bracket_reports_a_non_finite_value() {
+        let f: Arc<dyn Fn(f64) -> f64> = Arc::new(|x| if x > 1.0 { f64::NAN } else { -x });
+        let result = Bracketing::default().bracket(f, std::cmp::Ordering::Less, 0.0);
+        assert_eq!(result, Err(BracketingError::NonFiniteValue));
+    }
+
+    #[test]
+    fn bracket_rejects_invalid_parameters() {
+        let f: Arc<dyn Fn(f64) -> f64> = Arc::new(|x| (x - 3.0).powi(2));
+        let cases = [
+            (Bracketing::new(0.0, 2.0, 10, None), "step"),
+            (Bracketing::new(-0.1, 2.0, 10, None), "step"),
+            (Bracketing::new(f64::NAN, 2.0, 10, None), "step"),
+            (Bracketing::new(f64::INFINITY, 2.0, 10, None), "step"),
+            (Bracketing::new(0.1, 1.0, 10, None), "growth"),
+            (Bracketing::new(0.1, f64::NAN, 10, None), "growth"),
+            (Bracketing::new(0.1, 2.0, 0, None), "max_expansions"),
+            (Bracketing::new(0.1, 2.0, 10, Some((1.0, -1.0))), "bounds"),
+        ];
+        for (bracketing, name) in cases.iter() {
+            let result = bracketing.bracket(f.clone(), std::cmp::Ordering::Less, 0.0);
+            assert_eq!(result, Err(BracketingError::InvalidParameter(name)));
+        }
+    }
+
+    #[test]
+    fn bracket_stops_at_the_bounds() {
+        let f: Arc<dyn Fn(f64) -> f64> = Arc::new(|x| -x);
+        let (left, right, _) = Bracketing::new(0.1, 2.0, 100, Some((-1.0, 5.0)))
+            .bracket(f, std::cmp::Ordering::Less, 0.0)
+            .unwrap();
+        assert!(left < 5.0 && right == 5.0);
+    }
+
+    #[test]
+    fn search_fails_on_an_unbounded_function() {
+        let f: Arc<dyn Fn(f64) -> f64> = Arc::new(|x| x);
+        let result = Search::Mnimimum(0.0, f, Method::GoldenRatio, 1e-6, 100);
+        assert_eq!(result.err(), Some(BracketingError::UnboundedDirection));
+    }
+
+    fn methods() -> Vec<Method<f64>> {
+        let df: Arc<dyn Fn(f64) -> f64> = Arc::new(|x| 2.0 * (x - 1.5) - 1.0 / x);
+        vec![
+            Method::Fibonacci,
+            Method::GoldenRatio,
+            Method::Dichotomy { delta: 1e-10 },
+            Method::Brent,
+            Method::Newton {
+                df: df.clone(),
+                ddf: Arc::new(|x| 2.0 + 1.0 / (x * x)),
+            },
+            Method::Secant { df: df.clone() },
+            Method::Cubic { df },
+            Method::Piyavskii {
+                lipschitz: Lipschitz::Known(10.0),
+            },
+        ]
+    }
+
+    // `(x - 1.5)^2 - ln x` has its minimum at `(3 + sqrt 17) / 4`
+    fn f() -> Arc<dyn Fn(f64) -> f64> {
+        Arc::new(|x| (x - 1.5).powi(2) - x.ln())
+    }
+
+    #[test]
+    fn every_method_finds_an_interior_minimum_on_an_interval() {
+        use crate::searchers::extremum_searcher::Search as _;
+        let minimum = (3.0 + 17f64.sqrt()) / 4.0;
+        for method in methods() {
+            let x = Search::on_interval(0.5, 3.0, f(), std::cmp::Ordering::Less, method, 1e-8, 200)
+                .result()
+                .x();
+            assert!((x - minimum).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn every_method_stays_on_the_interval_and_finds_an_endpoint_extremum() {
+        use crate::searchers::extremum_searcher::Search as _;
+        for method in methods() {
+            let x = Search::on_interval(2.5, 4.0, f(), std::cmp::Ordering::Less, method, 1e-8, 200)
+                .result()
+                .x();
+            assert!((2.5..=4.0).contains(&x));
+            assert!((x - 2.5).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn maximum_is_the_minimum_of_the_negated_function() {
+        let minimum = Search::result(
+            0.0,
+            Arc::new(|x: f64| (x - 2.0).powi(2)),
+            std::cmp::Ordering::Less,
+            Method::Fibonacci,
+            1e-6,
+            100,
+        )
+        .unwrap();
+        let maximum = Search::Maximum(
+            0.0,
+            Arc::new(|x: f64| -(x - 2.0).powi(2)),
+            Method::Fibonacci,
+            1e-6,
+            100,
+        )
+        .unwrap();
+        assert!((minimum.x() - 2.0).abs() < 1e-5);
+        assert_eq!(minimum.x(), maximum.x());
+        assert_eq!(minimum.iters(), maximum.iters());
+        let same = Search::Mnimimum(
+            0.0,
+            Arc::new(|x: f64| (x - 2.0).powi(2)),
+            Method::Fibonacci,
+            1e-6,
+            100,
+        )
+        .unwrap();
+        assert_eq!(same.x(), minimum.x());
+    }
+
+    #[test]
+    fn only_the_global_method_certifies_a_gap() {
+        use crate::searchers::extremum_searcher::Search as _;
+        let minimum = (3.0 + 17f64.sqrt()) / 4.0;
+        for method in methods() {
+            let is_global = matches!(method, Method::Piyavskii { .. });
+            let mut search =
+                Search::on_interval(0.5, 3.0, f(), std::cmp::Ordering::Less, method, 1e-8, 200);
+            let x = search.result().x();
+            match search.gap() {
+                // the gap bounds how far the value found is from the minimum
+                Some(gap) => assert!(is_global && f()(x) - f()(minimum) <= gap),
+                None => assert!(!is_global),
+            }
+        }
+    }
+}
@@ -0,0 +1,148 @@
+use super::search::OneDimensionalSearcher;
+use crate::searchers::extremum_searcher;
+use crate::searchers::extremum_searcher::IterationResult;
+
+use nalgebra::RealField;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct Secant<Scalar> {
+    df: Arc<dyn Fn(Scalar) -> Scalar>,
+    left: Scalar,
+    right: Scalar,
+    x1: Scalar,
+    x2: Scalar,
+    df1: Scalar,
+    df2: Scalar,
+    dx: Scalar,
+    iters: usize,
+    func_calls: usize,
+    derivative_calls: usize,
+    eps: Scalar,
+    max_iters: usize,
+    comparator: std::cmp::Ordering,
+}
+
+impl<Scalar> Secant<Scalar>
+where
+    Scalar: RealField,
+{
+    pub fn new(
+        left: Scalar,
+        right: Scalar,
+        df: Arc<dyn Fn(Scalar) -> Scalar>,
+        comparator: std::cmp::Ordering,
+        eps: Scalar,
+        max_iters: usize,
+    ) -> Self {
+        let df1 = df(left);
+        let df2 = df(right);
+        Self {
+            df,
+            left,
+            right,
+            x1: left,
+            x2: right,
+            df1,
+            df2,
+            dx: Scalar::max_value(),
+            iters: 0,
+            func_calls: 0,
+            derivative_calls: 2,
+            eps,
+            max_iters,
+            comparator,
+        }
+    }
+}
+
+impl<Scalar> OneDimensionalSearcher<Scalar> for Secant<Scalar> where Scalar: RealField {}
+
+impl<Scalar> extremum_searcher::Search<Scalar> for Secant<Scalar>
+where
+    Scalar: RealField,
+{
+    fn iters(&self) -> usize {
+        self.iters
+    }
+    fn func_calls(&self) -> usize {
+        self.func_calls
+    }
+    fn derivative_calls(&self) -> usize {
+        self.derivative_calls
+    }
+    fn x(&self) -> Scalar {
+        self.x2
+    }
+    fn dx(&self) -> Scalar {
+        self.dx
+    }
+}
+
+impl<Scalar> Iterator for Secant<Scalar>
+where
+    Scalar: RealField,
+{
+    type Item = IterationResult<Scalar>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let is_extra =
+            self.dx.abs() < self.eps || self.df2 == self.df1 || self.iters >= self.max_iters;
+        // the secant slope of the derivative estimates the curvature, it is taken by absolute value
+        // like in newton, so that a step never goes away from the requested extremum
+        let direction = if self.comparator == std::cmp::Ordering::Less {
+            -self.df2
+        } else {
+            self.df2
+        };
+        let x = if self.df2 == self.df1 || self.x2 == self.x1 {
+            self.x2
+        } else {
+            self.x2 + direction * ((self.x2 - self.x1) / (self.df2 - self.df1)).abs()
+        };
+        let x = x.max(self.left).min(self.right);
+        self.dx = x - self.x2;
+        self.x1 = self.x2;
+        self.df1 = self.df2;
+        self.x2 = x;
+        self.df2 = (self.df)(self.x2);
+        self.iters += 1;
+        self.derivative_calls += 1;
+        Some(IterationResult::new(self.x2, self.dx, 0, is_extra).with_derivative_calls(1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::search::{self, Method};
+    use super::*;
+    use crate::searchers::extremum_searcher::Search as _;
+    use std::f64::consts::PI;
+
+    fn result(left: f64, right: f64, comparator: std::cmp::Ordering) -> f64 {
+        search::Search::on_interval(
+            left,
+            right,
+            Arc::new(f64::sin),
+            comparator,
+            Method::Secant {
+                df: Arc::new(f64::cos),
+            },
+            1e-10,
+            100,
+        )
+        .result()
+        .x()
+    }
+
+    #[test]
+    fn finds_the_requested_extremum() {
+        assert!((result(0.5, 2.5, std::cmp::Ordering::Greater) - PI / 2.0).abs() < 1e-8);
+        assert!((result(3.5, 5.5, std::cmp::Ordering::Less) - 3.0 * PI / 2.0).abs() < 1e-8);
+    }
+
+    #[test]
+    fn minimum_does_not_converge_to_a_maximum() {
+        let x = result(0.5, 2.5, std::cmp::Ordering::Less);
+        assert!(x.sin() < 1.0 - 1e-3);
+    }
+}
@@ -1,8 +1,8 @@
-use super::descent_methods;
-use super::descent_methods::Method;
+use super::descent_searchers;
+use super::descent_searchers::search::Method;
 use super::extremum_searcher;
-use super::extremum_searcher::{FinalResult, IterationResult};
-use nalgebra::{allocator::Allocator, DefaultAllocator, DimName, RealField, VectorN};
+use super::extremum_searcher::{FinalResult, IterationResult, SearchError};
+use nalgebra::{allocator::Allocator, DefaultAllocator, Dim, DimSub, Dynamic, RealField, VectorN};
 use std::sync::Arc;
 
 #[derive(Clone)]
@@ -15,7 +15,7 @@ pub enum BoundType {
 pub struct Bound<Scalar, Dimension>
 where
     Scalar: RealField,
-    Dimension: DimName,
+    Dimension: Dim,
     DefaultAllocator: Allocator<Scalar, Dimension>,
 {
     function: Arc<dyn Fn(VectorN<Scalar, Dimension>) -> Scalar>,
@@ -28,7 +28,7 @@ where
 impl<Scalar, Dimension> Bound<Scalar, Dimension>
 where
     Scalar: RealField,
-    Dimension: DimName,
+    Dimension: Dim,
     DefaultAllocator: Allocator<Scalar, Dimension>,
 {
     pub fn new(
@@ -51,8 +51,8 @@ where
 pub struct Search<Scalar, Dimension>
 where
     Scalar: RealField,
-    Dimension: DimName,
-    DefaultAllocator: Allocator<Scalar, Dimension>,
+    Dimension: Dim,
+    DefaultAllocator: Allocator<Scalar, Dimension> + Allocator<Scalar, Dimension, Dimension>,
 {
     x: VectorN<Scalar, Dimension>,
     dx: VectorN<Scalar, Dimension>,
@@ -60,7 +60,8 @@ where
     comparator: std::cmp::Ordering,
     func_calls: usize,
     iters: usize,
-    method: Method,
+    error: Option<SearchError>,
+    method: Method<Scalar, Dimension>,
     max_iters: usize,
     eps: Scalar,
     g: Vec<Bound<Scalar, Dimension>>,
@@ -70,24 +71,25 @@ where
 impl<Scalar, Dimension> Search<Scalar, Dimension>
 where
     Scalar: RealField,
-    Dimension: DimName,
-    DefaultAllocator: Allocator<Scalar, Dimension>,
+    Dimension: DimSub<Dynamic>,
+    DefaultAllocator: Allocator<Scalar, Dimension> + Allocator<Scalar, Dimension, Dimension>,
 {
     pub fn new(
         x0: VectorN<Scalar, Dimension>,
         f: Arc<dyn Fn(VectorN<Scalar, Dimension>) -> Scalar>,
         comparator: std::cmp::Ordering,
-        method: Method,
+        method: Method<Scalar, Dimension>,
         g: Vec<Bound<Scalar, Dimension>>,
         eps: Scalar,
         max_iters: usize,
     ) -> Self {
         Self {
+            dx: x0.map(|_| Scalar::max_value()),
             x: x0,
             f,
             comparator,
-            dx: VectorN::<Scalar, Dimension>::from_element(Scalar::max_value()),
             iters: 0,
+            error: None,
             func_calls: 0,
             method,
             max_iters,
@@ -100,33 +102,33 @@ where
         x0: VectorN<Scalar, Dimension>,
         f: Arc<dyn Fn(VectorN<Scalar, Dimension>) -> Scalar>,
         comparator: std::cmp::Ordering,
-        method: Method,
+        method: Method<Scalar, Dimension>,
         g: Vec<Bound<Scalar, Dimension>>,
         eps: Scalar,
         max_iters: usize,
-    ) -> FinalResult<VectorN<Scalar, Dimension>> {
+    ) -> Result<FinalResult<VectorN<Scalar, Dimension>>, SearchError> {
         use crate::searchers::extremum_searcher::Search;
-        Self::new(x0, f, comparator, method, g, eps, max_iters).result()
+        Self::new(x0, f, comparator, method, g, eps, max_iters).try_result()
     }
     pub fn Mnimimum(
         x0: VectorN<Scalar, Dimension>,
         f: Arc<dyn Fn(VectorN<Scalar, Dimension>) -> Scalar>,
-        method: Method,
+        method: Method<Scalar, Dimension>,
         g: Vec<Bound<Scalar, Dimension>>,
         eps: Scalar,
         max_iters: usize,
-    ) -> FinalResult<VectorN<Scalar, Dimension>> {
+    ) -> Result<FinalResult<VectorN<Scalar, Dimension>>, SearchError> {
         use crate::searchers::extremum_searcher::Search;
-        Self::new(x0, f, std::cmp::Ordering::Less, method, g, eps, max_iters).result()
+        Self::new(x0, f, std::cmp::Ordering::Less, method, g, eps, max_iters).try_result()
     }
     pub fn Maximum(
         x0: VectorN<Scalar, Dimension>,
         f: Arc<dyn Fn(VectorN<Scalar, Dimension>) -> Scalar>,
-        method: Method,
+        method: Method<Scalar, Dimension>,
         g: Vec<Bound<Scalar, Dimension>>,
         eps: Scalar,
         max_iters: usize,
-    ) -> FinalResult<VectorN<Scalar, Dimension>> {
+    ) -> Result<FinalResult<VectorN<Scalar, Dimension>>, SearchError> {
         use crate::searchers::extremum_searcher::Search;
         Self::new(
             x0,
@@ -137,7 +139,7 @@ where
             eps,
             max_iters,
         )
-        .result()
+        .try_result()
     }
 }
 
@@ -145,8 +147,8 @@ impl<Scalar, Dimension> extremum_searcher::Search<VectorN<Scalar, Dimension>>
     for Search<Scalar, Dimension>
 where
     Scalar: RealField,
-    Dimension: DimName,
-    DefaultAllocator: Allocator<Scalar, Dimension>,
+    Dimension: DimSub<Dynamic>,
+    DefaultAllocator: Allocator<Scalar, Dimension> + Allocator<Scalar, Dimension, Dimension>,
 {
     fn iters(&self) -> usize {
         self.iters
@@ -154,6 +156,9 @@ where
     fn func_calls(&self) -> usize {
         self.func_calls
     }
+    fn error(&self) -> Option<SearchError> {
+        self.error
+    }
     fn x(&self) -> VectorN<Scalar, Dimension> {
         self.x.clone()
     }
@@ -165,8 +170,8 @@ where
 impl<Scalar, Dimension> Iterator for Search<Scalar, Dimension>
 where
     Scalar: RealField,
-    Dimension: DimName,
-    DefaultAllocator: Allocator<Scalar, Dimension>,
+    Dimension: DimSub<Dynamic>,
+    DefaultAllocator: Allocator<Scalar, Dimension> + Allocator<Scalar, Dimension, Dimension>,
 {
     type Item = IterationResult<VectorN<Scalar, Dimension>>;
     fn next(&mut self) -> Option<Self::Item> {
@@ -192,7 +197,7 @@ where
         }
         let g = self.g.clone();
         let f = self.f.clone();
-        let result = descent_methods::Search::result(
+        let result = descent_searchers::search::Search::result(
             self.x.clone(),
             Arc::new(move |x: VectorN<Scalar, Dimension>| -> Scalar {
                 g.iter().fold(f(x.clone()), |result, i| {
@@ -204,6 +209,13 @@ where
             self.eps,
             self.max_iters,
         );
+        let result = match result {
+            Ok(result) => result,
+            Err(error) => {
+                self.error = Some(error);
+                return None;
+            }
+        };
         self.func_calls += result.func_calls();
         let x = self.x.clone();
         self.x = result.x();
@@ -235,11 +247,80 @@ where
             }
         });
         self.iters += 1;
-        return Some(Self::Item::new(
+        Some(Self::Item::new(
             self.x.clone(),
             self.dx.clone(),
             result.func_calls(),
             is_extra,
-        ));
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::searchers::line_searchers;
+    use crate::searchers::one_dimension_searchers;
+    use nalgebra::{Vector2, U2};
+
+    type Function = Arc<dyn Fn(Vector2<f64>) -> f64>;
+
+    fn gauss() -> Method<f64, U2> {
+        Method::Gauss {
+            line_search: line_searchers::search::Method::Exact {
+                method: one_dimension_searchers::search::Method::Fibonacci,
+                bracketing: one_dimension_searchers::search::Bracketing::default(),
+            },
+            selection: descent_searchers::search::Selection::Cyclic,
+            blocks: None,
+        }
+    }
+
+    #[test]
+    fn unequal_bound_moves_the_minimum_to_the_boundary() {
+        let f: Function = Arc::new(|x| (x[0] - 2.0).powi(2) + (x[1] - 2.0).powi(2));
+        // the penalty coefficient grows tenfold while the bound is violated
+        let g = vec![Bound::new(
+            Arc::new(|x: Vector2<f64>| x[0] - 1.0),
+            BoundType::Unequal,
+            Arc::new(|g: f64| g.max(0.0).powi(2)),
+            1.0,
+            Arc::new(|c| c * 10.0),
+        )];
+        let minimum = Search::Mnimimum(
+            Vector2::new(0.0, 0.0),
+            f.clone(),
+            gauss(),
+            g.clone(),
+            1e-3,
+            100,
+        )
+        .unwrap();
+        assert!((minimum.x() - Vector2::new(1.0, 2.0)).amax() < 1e-2);
+        let result = Search::result(
+            Vector2::new(0.0, 0.0),
+            f,
+            std::cmp::Ordering::Less,
+            gauss(),
+            g,
+            1e-3,
+            100,
+        )
+        .unwrap();
+        assert_eq!(result.x(), minimum.x());
+    }
+
+    #[test]
+    fn equal_bound_keeps_the_maximum_on_it() {
+        let f: Function = Arc::new(|x| -(x[0] - 2.0).powi(2) - (x[1] - 2.0).powi(2));
+        let g = vec![Bound::new(
+            Arc::new(|x: Vector2<f64>| x[0] - 1.0),
+            BoundType::Equal,
+            Arc::new(|g: f64| g.powi(2)),
+            -1.0,
+            Arc::new(|c| c * 10.0),
+        )];
+        let maximum = Search::Maximum(Vector2::new(0.0, 0.0), f, gauss(), g, 1e-3, 100).unwrap();
+        assert!((maximum.x() - Vector2::new(1.0, 2.0)).amax() < 1e-2);
     }
 }
@@ -2,15 +2,19 @@ use super::super::search::{Area, RandomSearcher};
 use crate::searchers::descent_searchers;
 use crate::searchers::descent_searchers::search::Method;
 use crate::searchers::extremum_searcher;
-use crate::searchers::extremum_searcher::IterationResult;
-use nalgebra::{allocator::Allocator, DefaultAllocator, DimName, RealField, VectorN};
+use crate::searchers::extremum_searcher::{IterationResult, SearchError};
+use nalgebra::{
+    allocator::Allocator, DefaultAllocator, DimName, DimSub, Dynamic, RealField, VectorN,
+};
 use std::sync::Arc;
 
-struct Second<Scalar, Dimension>
+pub struct Second<Scalar, Dimension>
 where
     Scalar: RealField,
-    Dimension: DimName,
-    DefaultAllocator: Allocator<Scalar, Dimension> + Allocator<(Scalar, Scalar), Dimension>,
+    Dimension: DimName + DimSub<Dynamic>,
+    DefaultAllocator: Allocator<Scalar, Dimension>
+        + Allocator<Scalar, Dimension, Dimension>
+        + Allocator<(Scalar, Scalar), Dimension>,
 {
     f: Arc<dyn Fn(VectorN<Scalar, Dimension>) -> Scalar>,
     comparator: std::cmp::Ordering,
@@ -18,26 +22,29 @@ where
     D: Area<Scalar, Dimension>,
     func_calls: usize,
     iters: usize,
+    error: Option<SearchError>,
     _f: Scalar,
     max_iters: usize,
-    method: Method,
+    method: Method<Scalar, Dimension>,
     eps: Scalar,
     got_result: bool,
 }
 impl<Scalar, Dimension> Second<Scalar, Dimension>
 where
     Scalar: RealField,
-    Dimension: DimName,
-    DefaultAllocator: Allocator<Scalar, Dimension> + Allocator<(Scalar, Scalar), Dimension>,
+    Dimension: DimName + DimSub<Dynamic>,
+    DefaultAllocator: Allocator<Scalar, Dimension>
+        + Allocator<Scalar, Dimension, Dimension>
+        + Allocator<(Scalar, Scalar), Dimension>,
 {
     pub fn new(
         mut D: Area<Scalar, Dimension>,
         f: Arc<dyn Fn(VectorN<Scalar, Dimension>) -> Scalar>,
         comparator: std::cmp::Ordering,
-        method: Method,
+        method: Method<Scalar, Dimension>,
         eps: Scalar,
         max_iters: usize,
-    ) -> Self {
+    ) -> Result<Self, SearchError> {
         let result = descent_searchers::search::Search::result(
             D.get_random_point(),
             f.clone(),
@@ -45,41 +52,49 @@ where
             method.clone(),
             eps,
             max_iters,
-        );
+        )?;
         let x = result.x();
         let _f = f(x.clone());
-        Self {
+        Ok(Self {
             comparator,
             x,
             D,
             f,
             _f,
             iters: 1,
+            error: None,
             func_calls: result.func_calls(),
             max_iters,
             method,
             eps,
             got_result: false,
-        }
+        })
     }
 }
 impl<Scalar, Dimension> RandomSearcher<Scalar, Dimension> for Second<Scalar, Dimension>
 where
     Scalar: RealField,
-    Dimension: DimName,
-    DefaultAllocator: Allocator<Scalar, Dimension> + Allocator<(Scalar, Scalar), Dimension>,
+    Dimension: DimName + DimSub<Dynamic>,
+    DefaultAllocator: Allocator<Scalar, Dimension>
+        + Allocator<Scalar, Dimension, Dimension>
+        + Allocator<(Scalar, Scalar), Dimension>,
 {
 }
 impl<Scalar, Dimension> extremum_searcher::Search<VectorN<Scalar, Dimension>>
     for Second<Scalar, Dimension>
 where
     Scalar: RealField,
-    Dimension: DimName,
-    DefaultAllocator: Allocator<Scalar, Dimension> + Allocator<(Scalar, Scalar), Dimension>,
+    Dimension: DimName + DimSub<Dynamic>,
+    DefaultAllocator: Allocator<Scalar, Dimension>
+        + Allocator<Scalar, Dimension, Dimension>
+        + Allocator<(Scalar, Scalar), Dimension>,
 {
     fn iters(&self) -> usize {
         self.iters
     }
+    fn error(&self) -> Option<SearchError> {
+        self.error
+    }
     fn func_calls(&self) -> usize {
         self.func_calls
     }
@@ -93,15 +108,17 @@ where
 impl<Scalar, Dimension> Iterator for Second<Scalar, Dimension>
 where
     Scalar: RealField,
-    Dimension: DimName,
-    DefaultAllocator: Allocator<Scalar, Dimension> + Allocator<(Scalar, Scalar), Dimension>,
+    Dimension: DimName + DimSub<Dynamic>,
+    DefaultAllocator: Allocator<Scalar, Dimension>
+        + Allocator<Scalar, Dimension, Dimension>
+        + Allocator<(Scalar, Scalar), Dimension>,
 {
     type Item = IterationResult<VectorN<Scalar, Dimension>>;
     fn next(&mut self) -> Option<Self::Item> {
         self.iters += 1;
         for _ in 0..self.max_iters {
             let result = super::super::search::Search::result(
-                self.D,
+                self.D.clone(),
                 self.f.clone(),
                 self.comparator,
                 super::super::search::Method::Simple,
@@ -109,12 +126,36 @@ where
                 self.eps,
                 self.max_iters,
             );
+            let result = match result {
+                Ok(result) => result,
+                Err(error) => {
+                    self.error = Some(error);
+                    return None;
+                }
+            };
             let x = result.x();
-            let dx = self.x.clone() - x.clone();
             let f = (self.f)(x.clone());
             self.func_calls += result.func_calls() + 1;
-            if self._f.partial_cmp(&f).unwrap() == self.comparator {
-                self._f = f;
+            if f.partial_cmp(&self._f) == Some(self.comparator) {
+                // the local descent is restarted from the better point
+                let result = match descent_searchers::search::Search::result(
+                    x,
+                    self.f.clone(),
+                    self.comparator,
+                    self.method.clone(),
+                    self.eps,
+                    self.max_iters,
+                ) {
+                    Ok(result) => result,
+                    Err(error) => {
+                        self.error = Some(error);
+                        return None;
+                    }
+                };
+                let x = result.x();
+                let dx = self.x.clone() - x.clone();
+                self._f = (self.f)(x.clone());
+                self.func_calls += result.func_calls() + 1;
                 self.x = x.clone();
                 return Some(IterationResult::new(x, dx, 1, self.got_result));
             }
@@ -1,9 +1,13 @@
+use super::super::descent_searchers;
 use super::super::extremum_searcher;
-use super::super::extremum_searcher::{FinalResult, IterationResult};
-use nalgebra::{allocator::Allocator, DefaultAllocator, DimName, RealField, VectorN};
+use super::super::extremum_searcher::{FinalResult, IterationResult, SearchError};
+use nalgebra::{
+    allocator::Allocator, DefaultAllocator, DimName, DimSub, Dynamic, RealField, VectorN,
+};
 use rand::Rng;
 use std::sync::Arc;
 
+#[derive(Clone)]
 pub struct Area<Scalar, Dimension>
 where
     Scalar: RealField,
@@ -59,11 +63,21 @@ where
 }
 
 #[derive(Clone)]
-pub enum Method {
+pub enum Method<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: DimName,
+    DefaultAllocator: Allocator<Scalar, Dimension> + Allocator<Scalar, Dimension, Dimension>,
+{
     Simple,
-    GlobalFirst,
-    GlobalSecond,
-    GlobalThird,
+    // local descents from random points, `method` is the descent used
+    GlobalFirst {
+        method: descent_searchers::search::Method<Scalar, Dimension>,
+    },
+    // one local descent, then simple random searches until a better point is found
+    GlobalSecond {
+        method: descent_searchers::search::Method<Scalar, Dimension>,
+    },
 }
 
 pub struct Search<Scalar, Dimension>
@@ -84,57 +98,69 @@ where
 impl<Scalar, Dimension> Search<Scalar, Dimension>
 where
     Scalar: RealField,
-    Dimension: DimName,
-    DefaultAllocator: Allocator<Scalar, Dimension> + Allocator<(Scalar, Scalar), Dimension>,
+    Dimension: DimName + DimSub<Dynamic>,
+    DefaultAllocator: Allocator<Scalar, Dimension>
+        + Allocator<Scalar, Dimension, Dimension>
+        + Allocator<(Scalar, Scalar), Dimension>,
 {
     pub fn new(
-        mut D: Area<Scalar, Dimension>,
+        D: Area<Scalar, Dimension>,
         f: Arc<dyn Fn(VectorN<Scalar, Dimension>) -> Scalar>,
         comparator: std::cmp::Ordering,
-        method: Method,
+        method: Method<Scalar, Dimension>,
         eps: Scalar,
         alpha: Scalar,
         max_iters: usize,
-    ) -> Self {
-        let m = match method {
-            Method::Simple => super::simple::Simple::new(
+    ) -> Result<Self, SearchError> {
+        let m: Box<
+            dyn RandomSearcher<
+                Scalar,
+                Dimension,
+                Item = IterationResult<VectorN<Scalar, Dimension>>,
+            >,
+        > = match method {
+            Method::Simple => Box::new(super::simple::Simple::new(
                 D,
                 f,
                 comparator,
                 VectorN::<Scalar, Dimension>::from_element(eps),
                 alpha,
-            ),
-            _ => todo! {},
+            )),
+            Method::GlobalFirst { method } => Box::new(super::global::first::First::new(
+                D, f, comparator, method, eps, max_iters,
+            )?),
+            Method::GlobalSecond { method } => Box::new(super::global::second::Second::new(
+                D, f, comparator, method, eps, max_iters,
+            )?),
         };
-        use crate::searchers::extremum_searcher::Search;
-        Self {
+        Ok(Self {
             x: m.x(),
             dx: m.dx(),
             func_calls: m.func_calls(),
             iters: 0,
-            method: Box::new(m),
-        }
+            method: m,
+        })
     }
     pub fn result(
-        mut D: Area<Scalar, Dimension>,
+        D: Area<Scalar, Dimension>,
         f: Arc<dyn Fn(VectorN<Scalar, Dimension>) -> Scalar>,
         comparator: std::cmp::Ordering,
-        method: Method,
+        method: Method<Scalar, Dimension>,
         eps: Scalar,
         alpha: Scalar,
         max_iters: usize,
-    ) -> FinalResult<VectorN<Scalar, Dimension>> {
+    ) -> Result<FinalResult<VectorN<Scalar, Dimension>>, SearchError> {
         use crate::searchers::extremum_searcher::Search;
-        Self::new(D, f, comparator, method, eps, alpha, max_iters).result()
+        Self::new(D, f, comparator, method, eps, alpha, max_iters)?.try_result()
     }
     pub fn Mnimimum(
-        mut D: Area<Scalar, Dimension>,
+        D: Area<Scalar, Dimension>,
         f: Arc<dyn Fn(VectorN<Scalar, Dimension>) -> Scalar>,
-        method: Method,
+        method: Method<Scalar, Dimension>,
         eps: Scalar,
         alpha: Scalar,
         max_iters: usize,
-    ) -> FinalResult<VectorN<Scalar, Dimension>> {
+    ) -> Result<FinalResult<VectorN<Scalar, Dimension>>, SearchError> {
         use crate::searchers::extremum_searcher::Search;
         Self::new(
             D,
@@ -144,17 +170,17 @@ where
             eps,
             alpha,
             max_iters,
-        )
-        .result()
+        )?
+        .try_result()
     }
     pub fn Maximum(
-        mut D: Area<Scalar, Dimension>,
+        D: Area<Scalar, Dimension>,
         f: Arc<dyn Fn(VectorN<Scalar, Dimension>) -> Scalar>,
-        method: Method,
+        method: Method<Scalar, Dimension>,
         eps: Scalar,
         alpha: Scalar,
         max_iters: usize,
-    ) -> FinalResult<VectorN<Scalar, Dimension>> {
+    ) -> Result<FinalResult<VectorN<Scalar, Dimension>>, SearchError> {
         use crate::searchers::extremum_searcher::Search;
         Self::new(
             D,
@@ -164,8 +190,8 @@ where
             eps,
             alpha,
             max_iters,
-        )
-        .result()
+        )?
+        .try_result()
     }
 }
 
@@ -182,6 +208,9 @@ where
     fn func_calls(&self) -> usize {
         self.func_calls
     }
+    fn error(&self) -> Option<SearchError> {
+        self.method.error()
+    }
     fn x(&self) -> VectorN<Scalar, Dimension> {
         self.x.clone()
     }
@@ -215,3 +244,73 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::searchers::line_searchers;
+    use crate::searchers::one_dimension_searchers;
+    use nalgebra::{Vector2, U2};
+
+    type Function = Arc<dyn Fn(Vector2<f64>) -> f64>;
+
+    fn area() -> Area<f64, U2> {
+        Area::new(Vector2::new((-5.0, 5.0), (-5.0, 5.0)))
+    }
+
+    // the minimum is at (1, -2)
+    fn f() -> Function {
+        Arc::new(|x| (x[0] - 1.0).powi(2) + (x[1] + 2.0).powi(2))
+    }
+
+    fn gauss() -> descent_searchers::search::Method<f64, U2> {
+        descent_searchers::search::Method::Gauss {
+            line_search: line_searchers::search::Method::Exact {
+                method: one_dimension_searchers::search::Method::Fibonacci,
+                bracketing: one_dimension_searchers::search::Bracketing::default(),
+            },
+            selection: descent_searchers::search::Selection::Cyclic,
+            blocks: None,
+        }
+    }
+
+    #[test]
+    fn points_are_sampled_in_the_area() {
+        let mut D = area();
+        assert_eq!(D.V(), 100.0);
+        for _ in 0..100 {
+            let x = D.get_random_point();
+            assert!(x.iter().all(|&xi| (-5.0..=5.0).contains(&xi)));
+        }
+    }
+
+    #[test]
+    fn simple_search_keeps_the_best_point() {
+        // about 1800 points, one of them falls where f < 1 with a probability of 1 - e^-58
+        let minimum = Search::Mnimimum(area(), f(), Method::Simple, 0.5, 0.99, 0).unwrap();
+        assert!(f()(minimum.x()) < 1.0);
+        let maximum = Search::Maximum(
+            area(),
+            Arc::new(|x| -(x[0] - 1.0).powi(2) - (x[1] + 2.0).powi(2)),
+            Method::Simple,
+            0.5,
+            0.99,
+            0,
+        )
+        .unwrap();
+        assert!(f()(maximum.x()) < 1.0);
+    }
+
+    #[test]
+    fn global_searches_find_the_minimum() {
+        for method in [
+            Method::GlobalFirst { method: gauss() },
+            Method::GlobalSecond { method: gauss() },
+        ] {
+            let result =
+                Search::result(area(), f(), std::cmp::Ordering::Less, method, 0.1, 0.99, 20)
+                    .unwrap();
+            assert!((result.x() - Vector2::new(1.0, -2.0)).amax() < 0.1);
+        }
+    }
+}
@@ -27,8 +27,6 @@ where
     DefaultAllocator: Allocator<Scalar, Dimension> + Allocator<(Scalar, Scalar), Dimension>,
 {
     pub fn new(
-        x0: VectorN<Scalar, Dimension>,
-        f0: Scalar,
         mut D: Area<Scalar, Dimension>,
         f: Arc<dyn Fn(VectorN<Scalar, Dimension>) -> Scalar>,
         comparator: std::cmp::Ordering,
@@ -97,12 +95,12 @@ where
         let x = self.D.get_random_point();
         let dx = self.x.clone() - x.clone();
         let f = (self.f)(x.clone());
-        if self._f.partial_cmp(&f).unwrap() == self.comparator {
+        if f.partial_cmp(&self._f) == Some(self.comparator) {
             self._f = f;
             self.x = x.clone();
         }
         self.func_calls += 1;
         self.iters += 1;
-        Some(IterationResult::new(x, dx, 1, is_extra))
+        Some(IterationResult::new(self.x.clone(), dx, 1, is_extra))
     }
 }
@@ -0,0 +1,95 @@
+use super::search::RootSearcher;
+use crate::searchers::extremum_searcher;
+use crate::searchers::extremum_searcher::IterationResult;
+
+use nalgebra::RealField;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct Bisection<Scalar> {
+    g: Arc<dyn Fn(Scalar) -> Scalar>,
+    left: Scalar,
+    right: Scalar,
+    gl: Scalar,
+    gx: Scalar,
+    dx: Scalar,
+    iters: usize,
+    func_calls: usize,
+    eps: Scalar,
+    max_iters: usize,
+}
+
+impl<Scalar> Bisection<Scalar>
+where
+    Scalar: RealField,
+{
+    pub fn new(
+        left: Scalar,
+        right: Scalar,
+        gl: Scalar,
+        g: Arc<dyn Fn(Scalar) -> Scalar>,
+        eps: Scalar,
+        max_iters: usize,
+    ) -> Self {
+        Self {
+            g,
+            left,
+            right,
+            gl,
+            gx: Scalar::max_value(),
+            dx: Scalar::max_value(),
+            iters: 0,
+            func_calls: 0,
+            eps,
+            max_iters,
+        }
+    }
+}
+
+impl<Scalar> RootSearcher<Scalar> for Bisection<Scalar> where Scalar: RealField {}
+
+impl<Scalar> extremum_searcher::Search<Scalar> for Bisection<Scalar>
+where
+    Scalar: RealField,
+{
+    fn iters(&self) -> usize {
+        self.iters
+    }
+    fn func_calls(&self) -> usize {
+        self.func_calls
+    }
+    fn x(&self) -> Scalar {
+        (self.right + self.left) / Scalar::from_i8(2).unwrap()
+    }
+    fn dx(&self) -> Scalar {
+        self.dx
+    }
+}
+
+impl<Scalar> Iterator for Bisection<Scalar>
+where
+    Scalar: RealField,
+{
+    type Item = IterationResult<Scalar>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let is_extra = (self.right - self.left).abs() < self.eps
+            || self.gx.is_zero()
+            || self.iters >= self.max_iters;
+        let _x = (self.right + self.left) / Scalar::from_i8(2).unwrap();
+        self.gx = (self.g)(_x);
+        if self.gx.is_zero() {
+            self.left = _x;
+            self.right = _x;
+        } else if (self.gx > Scalar::zero()) == (self.gl > Scalar::zero()) {
+            self.left = _x;
+            self.gl = self.gx;
+        } else {
+            self.right = _x;
+        }
+        let x = (self.right + self.left) / Scalar::from_i8(2).unwrap();
+        self.dx = x - _x;
+        self.iters += 1;
+        self.func_calls += 1;
+        Some(IterationResult::new(x, self.dx, 1, is_extra))
+    }
+}
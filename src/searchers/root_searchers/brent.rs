@@ -0,0 +1,150 @@
+use super::search::RootSearcher;
+use crate::searchers::extremum_searcher;
+use crate::searchers::extremum_searcher::IterationResult;
+
+use nalgebra::RealField;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct Brent<Scalar> {
+    g: Arc<dyn Fn(Scalar) -> Scalar>,
+    a: Scalar,
+    b: Scalar,
+    c: Scalar,
+    ga: Scalar,
+    gb: Scalar,
+    gc: Scalar,
+    d: Scalar,
+    e: Scalar,
+    dx: Scalar,
+    iters: usize,
+    func_calls: usize,
+    eps: Scalar,
+    max_iters: usize,
+}
+
+impl<Scalar> Brent<Scalar>
+where
+    Scalar: RealField,
+{
+    pub fn new(
+        left: Scalar,
+        right: Scalar,
+        gl: Scalar,
+        gr: Scalar,
+        g: Arc<dyn Fn(Scalar) -> Scalar>,
+        eps: Scalar,
+        max_iters: usize,
+    ) -> Self {
+        Self {
+            g,
+            a: left,
+            b: right,
+            c: right,
+            ga: gl,
+            gb: gr,
+            gc: gr,
+            d: right - left,
+            e: right - left,
+            dx: Scalar::max_value(),
+            iters: 0,
+            func_calls: 0,
+            eps,
+            max_iters,
+        }
+    }
+}
+
+impl<Scalar> RootSearcher<Scalar> for Brent<Scalar> where Scalar: RealField {}
+
+impl<Scalar> extremum_searcher::Search<Scalar> for Brent<Scalar>
+where
+    Scalar: RealField,
+{
+    fn iters(&self) -> usize {
+        self.iters
+    }
+    fn func_calls(&self) -> usize {
+        self.func_calls
+    }
+    fn x(&self) -> Scalar {
+        self.b
+    }
+    fn dx(&self) -> Scalar {
+        self.dx
+    }
+}
+
+impl<Scalar> Iterator for Brent<Scalar>
+where
+    Scalar: RealField,
+{
+    type Item = IterationResult<Scalar>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let two = Scalar::from_i8(2).unwrap();
+        // `c` is kept on the other side of the root from `b`, `b` is the best estimate
+        if (self.gb > Scalar::zero()) == (self.gc > Scalar::zero()) {
+            self.c = self.a;
+            self.gc = self.ga;
+            self.d = self.b - self.a;
+            self.e = self.d;
+        }
+        if self.gc.abs() < self.gb.abs() {
+            self.a = self.b;
+            self.b = self.c;
+            self.c = self.a;
+            self.ga = self.gb;
+            self.gb = self.gc;
+            self.gc = self.ga;
+        }
+        let tol = self.eps / two;
+        let middle = (self.c - self.b) / two;
+        let is_extra = middle.abs() <= tol || self.gb.is_zero() || self.iters >= self.max_iters;
+        let _b = self.b;
+
+        if self.e.abs() >= tol && self.ga.abs() > self.gb.abs() {
+            let s = self.gb / self.ga;
+            let (mut p, mut q) = if self.a == self.c {
+                (two * middle * s, Scalar::one() - s)
+            } else {
+                let q = self.ga / self.gc;
+                let r = self.gb / self.gc;
+                (
+                    s * (two * middle * q * (q - r) - (self.b - self.a) * (r - Scalar::one())),
+                    (q - Scalar::one()) * (r - Scalar::one()) * (s - Scalar::one()),
+                )
+            };
+            if p > Scalar::zero() {
+                q = -q;
+            }
+            p = p.abs();
+            let min1 = Scalar::from_i8(3).unwrap() * middle * q - (tol * q).abs();
+            let min2 = (self.e * q).abs();
+            if two * p < min1.min(min2) {
+                self.e = self.d;
+                self.d = p / q;
+            } else {
+                self.d = middle;
+                self.e = self.d;
+            }
+        } else {
+            self.d = middle;
+            self.e = self.d;
+        }
+        self.a = self.b;
+        self.ga = self.gb;
+        self.b += if self.d.abs() > tol {
+            self.d
+        } else if middle >= Scalar::zero() {
+            tol
+        } else {
+            -tol
+        };
+        self.gb = (self.g)(self.b);
+
+        self.dx = self.b - _b;
+        self.iters += 1;
+        self.func_calls += 1;
+        Some(IterationResult::new(self.b, self.dx, 1, is_extra))
+    }
+}
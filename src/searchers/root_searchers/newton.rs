@@ -0,0 +1,99 @@
+use super::search::RootSearcher;
+use crate::searchers::extremum_searcher;
+use crate::searchers::extremum_searcher::IterationResult;
+
+use nalgebra::RealField;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct Newton<Scalar> {
+    g: Arc<dyn Fn(Scalar) -> Scalar>,
+    dg: Arc<dyn Fn(Scalar) -> Scalar>,
+    left: Scalar,
+    right: Scalar,
+    x: Scalar,
+    dx: Scalar,
+    iters: usize,
+    func_calls: usize,
+    derivative_calls: usize,
+    eps: Scalar,
+    max_iters: usize,
+}
+
+impl<Scalar> Newton<Scalar>
+where
+    Scalar: RealField,
+{
+    // starts from the middle of the interval, the steps are clamped to it
+    pub fn new(
+        left: Scalar,
+        right: Scalar,
+        g: Arc<dyn Fn(Scalar) -> Scalar>,
+        dg: Arc<dyn Fn(Scalar) -> Scalar>,
+        eps: Scalar,
+        max_iters: usize,
+    ) -> Self {
+        Self {
+            g,
+            dg,
+            left,
+            right,
+            x: (right + left) / Scalar::from_i8(2).unwrap(),
+            dx: Scalar::max_value(),
+            iters: 0,
+            func_calls: 0,
+            derivative_calls: 0,
+            eps,
+            max_iters,
+        }
+    }
+}
+
+impl<Scalar> RootSearcher<Scalar> for Newton<Scalar> where Scalar: RealField {}
+
+impl<Scalar> extremum_searcher::Search<Scalar> for Newton<Scalar>
+where
+    Scalar: RealField,
+{
+    fn iters(&self) -> usize {
+        self.iters
+    }
+    fn func_calls(&self) -> usize {
+        self.func_calls
+    }
+    fn derivative_calls(&self) -> usize {
+        self.derivative_calls
+    }
+    fn x(&self) -> Scalar {
+        self.x
+    }
+    fn dx(&self) -> Scalar {
+        self.dx
+    }
+}
+
+impl<Scalar> Iterator for Newton<Scalar>
+where
+    Scalar: RealField,
+{
+    type Item = IterationResult<Scalar>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let gx = (self.g)(self.x);
+        let dgx = (self.dg)(self.x);
+        let is_extra = gx.abs() < self.eps
+            || self.dx.abs() < self.eps
+            || dgx.is_zero()
+            || self.iters >= self.max_iters;
+        let x = if dgx.is_zero() {
+            self.x
+        } else {
+            (self.x - gx / dgx).max(self.left).min(self.right)
+        };
+        self.dx = x - self.x;
+        self.x = x;
+        self.iters += 1;
+        self.func_calls += 1;
+        self.derivative_calls += 1;
+        Some(IterationResult::new(self.x, self.dx, 1, is_extra).with_derivative_calls(1))
+    }
+}
@@ -0,0 +1,122 @@
+use super::search::RootSearcher;
+use crate::searchers::extremum_searcher;
+use crate::searchers::extremum_searcher::IterationResult;
+
+use nalgebra::RealField;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct RegulaFalsi<Scalar> {
+    g: Arc<dyn Fn(Scalar) -> Scalar>,
+    left: Scalar,
+    right: Scalar,
+    gl: Scalar,
+    gr: Scalar,
+    x: Scalar,
+    gx: Scalar,
+    illinois: bool,
+    retained: std::cmp::Ordering,
+    dx: Scalar,
+    iters: usize,
+    func_calls: usize,
+    eps: Scalar,
+    max_iters: usize,
+}
+
+impl<Scalar> RegulaFalsi<Scalar>
+where
+    Scalar: RealField,
+{
+    // the Illinois modification halves the value at the end that is kept twice in a row
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        left: Scalar,
+        right: Scalar,
+        gl: Scalar,
+        gr: Scalar,
+        g: Arc<dyn Fn(Scalar) -> Scalar>,
+        illinois: bool,
+        eps: Scalar,
+        max_iters: usize,
+    ) -> Self {
+        let (x, gx) = if gl.abs() < gr.abs() {
+            (left, gl)
+        } else {
+            (right, gr)
+        };
+        Self {
+            g,
+            left,
+            right,
+            gl,
+            gr,
+            x,
+            gx,
+            illinois,
+            retained: std::cmp::Ordering::Equal,
+            dx: Scalar::max_value(),
+            iters: 0,
+            func_calls: 0,
+            eps,
+            max_iters,
+        }
+    }
+}
+
+impl<Scalar> RootSearcher<Scalar> for RegulaFalsi<Scalar> where Scalar: RealField {}
+
+impl<Scalar> extremum_searcher::Search<Scalar> for RegulaFalsi<Scalar>
+where
+    Scalar: RealField,
+{
+    fn iters(&self) -> usize {
+        self.iters
+    }
+    fn func_calls(&self) -> usize {
+        self.func_calls
+    }
+    fn x(&self) -> Scalar {
+        self.x
+    }
+    fn dx(&self) -> Scalar {
+        self.dx
+    }
+}
+
+impl<Scalar> Iterator for RegulaFalsi<Scalar>
+where
+    Scalar: RealField,
+{
+    type Item = IterationResult<Scalar>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let is_extra =
+            self.gx.abs() < self.eps || self.dx.abs() < self.eps || self.iters >= self.max_iters;
+        // equal values only happen when both ends are the root
+        let x = if self.gr == self.gl {
+            self.x
+        } else {
+            (self.left * self.gr - self.right * self.gl) / (self.gr - self.gl)
+        };
+        self.gx = (self.g)(x);
+        if (self.gx > Scalar::zero()) == (self.gr > Scalar::zero()) {
+            self.right = x;
+            self.gr = self.gx;
+            if self.illinois && self.retained == std::cmp::Ordering::Less {
+                self.gl /= Scalar::from_i8(2).unwrap();
+            }
+            self.retained = std::cmp::Ordering::Less;
+        } else {
+            self.left = x;
+            self.gl = self.gx;
+            if self.illinois && self.retained == std::cmp::Ordering::Greater {
+                self.gr /= Scalar::from_i8(2).unwrap();
+            }
+            self.retained = std::cmp::Ordering::Greater;
+        }
+        self.dx = x - self.x;
+        self.x = x;
+        self.iters += 1;
+        self.func_calls += 1;
+        Some(IterationResult::new(self.x, self.dx, 1, is_extra))
+    }
+}
@@ -0,0 +1,217 @@
+use crate::searchers::extremum_searcher;
+use crate::searchers::extremum_searcher::{FinalResult, IterationResult, SearchError};
+
+use nalgebra::RealField;
+use std::sync::Arc;
+
+pub trait RootSearcher<Scalar>: extremum_searcher::Search<Scalar>
+where
+    Scalar: Clone,
+{
+}
+
+#[derive(Clone)]
+pub enum Method<Scalar> {
+    Bisection,
+    RegulaFalsi,
+    Illinois,
+    Secant,
+    Newton { dg: Arc<dyn Fn(Scalar) -> Scalar> },
+    Brent,
+}
+
+pub struct Search<Scalar>
+where
+    Scalar: RealField,
+{
+    x: Scalar,
+    dx: Scalar,
+    func_calls: usize,
+    derivative_calls: usize,
+    iters: usize,
+    method: Box<dyn RootSearcher<Scalar>>,
+}
+
+impl<Scalar> Search<Scalar>
+where
+    Scalar: RealField,
+{
+    pub fn new(
+        left: Scalar,
+        right: Scalar,
+        g: Arc<dyn Fn(Scalar) -> Scalar>,
+        method: Method<Scalar>,
+        eps: Scalar,
+        max_iters: usize,
+    ) -> Result<Self, SearchError> {
+        let (gl, gr, func_calls) = match method {
+            Method::Newton { .. } => (Scalar::zero(), Scalar::zero(), 0),
+            _ => (g(left), g(right), 2),
+        };
+        let has_sign_change = !(gl > Scalar::zero() && gr > Scalar::zero()
+            || gl < Scalar::zero() && gr < Scalar::zero());
+        // a zero at an end of the interval is the root, the bracketing methods start and stop there
+        let (left, right, gl, gr) = match method {
+            Method::Secant | Method::Newton { .. } => (left, right, gl, gr),
+            _ if gl.is_zero() => (left, left, gl, gl),
+            _ if gr.is_zero() => (right, right, gr, gr),
+            _ => (left, right, gl, gr),
+        };
+        let m: Box<dyn RootSearcher<Scalar>> = match method {
+            Method::Secant => Box::new(super::secant::Secant::new(
+                left, right, gl, gr, g, eps, max_iters,
+            )),
+            Method::Newton { dg } => Box::new(super::newton::Newton::new(
+                left, right, g, dg, eps, max_iters,
+            )),
+            _ if !has_sign_change => return Err(SearchError::NoSignChange),
+            Method::Bisection => Box::new(super::bisection::Bisection::new(
+                left, right, gl, g, eps, max_iters,
+            )),
+            Method::RegulaFalsi => Box::new(super::regula_falsi::RegulaFalsi::new(
+                left, right, gl, gr, g, false, eps, max_iters,
+            )),
+            Method::Illinois => Box::new(super::regula_falsi::RegulaFalsi::new(
+                left, right, gl, gr, g, true, eps, max_iters,
+            )),
+            Method::Brent => Box::new(super::brent::Brent::new(
+                left, right, gl, gr, g, eps, max_iters,
+            )),
+        };
+        Ok(Self {
+            x: m.x(),
+            dx: Scalar::max_value(),
+            func_calls: func_calls + m.func_calls(),
+            derivative_calls: m.derivative_calls(),
+            iters: 0,
+            method: m,
+        })
+    }
+    pub fn result(
+        left: Scalar,
+        right: Scalar,
+        g: Arc<dyn Fn(Scalar) -> Scalar>,
+        method: Method<Scalar>,
+        eps: Scalar,
+        max_iters: usize,
+    ) -> Result<FinalResult<Scalar>, SearchError> {
+        use crate::searchers::extremum_searcher::Search;
+        Self::new(left, right, g, method, eps, max_iters)?.try_result()
+    }
+}
+
+impl<Scalar> extremum_searcher::Search<Scalar> for Search<Scalar>
+where
+    Scalar: RealField,
+{
+    fn iters(&self) -> usize {
+        self.iters
+    }
+    fn error(&self) -> Option<SearchError> {
+        self.method.error()
+    }
+    fn func_calls(&self) -> usize {
+        self.func_calls
+    }
+    fn derivative_calls(&self) -> usize {
+        self.derivative_calls
+    }
+    fn x(&self) -> Scalar {
+        self.x
+    }
+    fn dx(&self) -> Scalar {
+        self.dx
+    }
+}
+
+impl<Scalar> Iterator for Search<Scalar>
+where
+    Scalar: RealField,
+{
+    type Item = IterationResult<Scalar>;
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.method.next() {
+            Some(r) => {
+                self.iters += 1;
+                self.x = r.x();
+                self.dx = r.dx();
+                self.func_calls += r.func_calls();
+                self.derivative_calls += r.derivative_calls();
+                Some(
+                    IterationResult::new(self.x, self.dx, r.func_calls(), r.is_extra())
+                        .with_derivative_calls(r.derivative_calls()),
+                )
+            }
+            None => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bracketing() -> Vec<Method<f64>> {
+        vec![
+            Method::Bisection,
+            Method::RegulaFalsi,
+            Method::Illinois,
+            Method::Brent,
+        ]
+    }
+
+    #[test]
+    fn bracketing_methods_find_the_root() {
+        for method in bracketing() {
+            let g: Arc<dyn Fn(f64) -> f64> = Arc::new(|x| x * x - 2.0);
+            let result = Search::result(0.0, 2.0, g, method, 1e-10, 200).unwrap();
+            assert!((result.x() - 2f64.sqrt()).abs() < 1e-8);
+        }
+    }
+
+    #[test]
+    fn zero_at_an_end_is_returned() {
+        for method in bracketing() {
+            let g: Arc<dyn Fn(f64) -> f64> = Arc::new(|x| x - 1.0);
+            assert_eq!(
+                Search::result(1.0, 3.0, g.clone(), method.clone(), 1e-10, 200)
+                    .unwrap()
+                    .x(),
+                1.0
+            );
+            assert_eq!(
+                Search::result(-1.0, 1.0, g, method, 1e-10, 200)
+                    .unwrap()
+                    .x(),
+                1.0
+            );
+        }
+    }
+
+    #[test]
+    fn no_sign_change_is_an_error() {
+        let g: Arc<dyn Fn(f64) -> f64> = Arc::new(|x| x * x + 1.0);
+        assert_eq!(
+            Search::result(-1.0, 1.0, g, Method::Bisection, 1e-10, 200).err(),
+            Some(SearchError::NoSignChange)
+        );
+    }
+
+    #[test]
+    fn secant_and_newton_find_the_root() {
+        let g: Arc<dyn Fn(f64) -> f64> = Arc::new(|x| x.powi(3) - 2.0 * x - 5.0);
+        let dg: Arc<dyn Fn(f64) -> f64> = Arc::new(|x| 3.0 * x.powi(2) - 2.0);
+        for method in [Method::Secant, Method::Newton { dg }] {
+            let result = Search::result(2.0, 3.0, g.clone(), method, 1e-12, 100).unwrap();
+            assert!(g(result.x()).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn newton_stays_in_the_interval() {
+        let g: Arc<dyn Fn(f64) -> f64> = Arc::new(f64::atan);
+        let dg: Arc<dyn Fn(f64) -> f64> = Arc::new(|x| 1.0 / (1.0 + x * x));
+        let search = Search::new(-2.0, 8.0, g, Method::Newton { dg }, 1e-12, 50).unwrap();
+        assert!(search.take(50).all(|r| r.x() >= -2.0 && r.x() <= 8.0));
+    }
+}
@@ -0,0 +1,93 @@
+use super::search::RootSearcher;
+use crate::searchers::extremum_searcher;
+use crate::searchers::extremum_searcher::IterationResult;
+
+use nalgebra::RealField;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct Secant<Scalar> {
+    g: Arc<dyn Fn(Scalar) -> Scalar>,
+    x1: Scalar,
+    x2: Scalar,
+    g1: Scalar,
+    g2: Scalar,
+    dx: Scalar,
+    iters: usize,
+    func_calls: usize,
+    eps: Scalar,
+    max_iters: usize,
+}
+
+impl<Scalar> Secant<Scalar>
+where
+    Scalar: RealField,
+{
+    pub fn new(
+        x1: Scalar,
+        x2: Scalar,
+        g1: Scalar,
+        g2: Scalar,
+        g: Arc<dyn Fn(Scalar) -> Scalar>,
+        eps: Scalar,
+        max_iters: usize,
+    ) -> Self {
+        Self {
+            g,
+            x1,
+            x2,
+            g1,
+            g2,
+            dx: Scalar::max_value(),
+            iters: 0,
+            func_calls: 0,
+            eps,
+            max_iters,
+        }
+    }
+}
+
+impl<Scalar> RootSearcher<Scalar> for Secant<Scalar> where Scalar: RealField {}
+
+impl<Scalar> extremum_searcher::Search<Scalar> for Secant<Scalar>
+where
+    Scalar: RealField,
+{
+    fn iters(&self) -> usize {
+        self.iters
+    }
+    fn func_calls(&self) -> usize {
+        self.func_calls
+    }
+    fn x(&self) -> Scalar {
+        self.x2
+    }
+    fn dx(&self) -> Scalar {
+        self.dx
+    }
+}
+
+impl<Scalar> Iterator for Secant<Scalar>
+where
+    Scalar: RealField,
+{
+    type Item = IterationResult<Scalar>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let is_extra = self.g2.abs() < self.eps
+            || self.dx.abs() < self.eps
+            || self.g2 == self.g1
+            || self.iters >= self.max_iters;
+        self.dx = if self.g2 == self.g1 {
+            Scalar::zero()
+        } else {
+            -self.g2 * (self.x2 - self.x1) / (self.g2 - self.g1)
+        };
+        self.x1 = self.x2;
+        self.g1 = self.g2;
+        self.x2 += self.dx;
+        self.g2 = (self.g)(self.x2);
+        self.iters += 1;
+        self.func_calls += 1;
+        Some(IterationResult::new(self.x2, self.dx, 1, is_extra))
+    }
+}
@@ -0,0 +1,130 @@
+use super::search::{Objective, Sampler, Schedule, StochasticSearcher};
+use crate::searchers::extremum_searcher;
+use crate::searchers::extremum_searcher::IterationResult;
+use nalgebra::{allocator::Allocator, DefaultAllocator, Dim, RealField, VectorN};
+
+pub struct AdaGrad<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: Dim,
+    DefaultAllocator: Allocator<Scalar, Dimension>,
+{
+    x: VectorN<Scalar, Dimension>,
+    // displacement over the last epoch
+    dx: VectorN<Scalar, Dimension>,
+    // sum of the squared gradients
+    r: VectorN<Scalar, Dimension>,
+    delta: Scalar,
+    sign: Scalar,
+    derivative_calls: usize,
+    iters: usize,
+    objective: Objective<Scalar, Dimension>,
+    schedule: Schedule<Scalar>,
+    sampler: Sampler,
+    eps: Scalar,
+    max_iters: usize,
+}
+
+impl<Scalar, Dimension> AdaGrad<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: Dim,
+    DefaultAllocator: Allocator<Scalar, Dimension>,
+{
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        x0: VectorN<Scalar, Dimension>,
+        objective: Objective<Scalar, Dimension>,
+        comparator: std::cmp::Ordering,
+        delta: Scalar,
+        schedule: Schedule<Scalar>,
+        sampler: Sampler,
+        eps: Scalar,
+        max_iters: usize,
+    ) -> Self {
+        let sign = if comparator == std::cmp::Ordering::Less {
+            Scalar::one()
+        } else {
+            -Scalar::one()
+        };
+        Self {
+            dx: x0.map(|_| Scalar::max_value()),
+            r: x0.map(|_| Scalar::zero()),
+            delta,
+            x: x0,
+            sign,
+            objective,
+            schedule,
+            sampler,
+            eps,
+            iters: 0,
+            derivative_calls: 0,
+            max_iters,
+        }
+    }
+}
+
+impl<Scalar, Dimension> StochasticSearcher<Scalar, Dimension> for AdaGrad<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: Dim,
+    DefaultAllocator: Allocator<Scalar, Dimension>,
+{
+    fn rate(&self) -> Scalar {
+        self.schedule.rate(self.iters)
+    }
+}
+
+impl<Scalar, Dimension> extremum_searcher::Search<VectorN<Scalar, Dimension>>
+    for AdaGrad<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: Dim,
+    DefaultAllocator: Allocator<Scalar, Dimension>,
+{
+    fn iters(&self) -> usize {
+        self.iters
+    }
+    fn func_calls(&self) -> usize {
+        0
+    }
+    fn derivative_calls(&self) -> usize {
+        self.derivative_calls
+    }
+    fn x(&self) -> VectorN<Scalar, Dimension> {
+        self.x.clone()
+    }
+    fn dx(&self) -> VectorN<Scalar, Dimension> {
+        self.dx.clone()
+    }
+}
+
+impl<Scalar, Dimension> Iterator for AdaGrad<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: Dim,
+    DefaultAllocator: Allocator<Scalar, Dimension>,
+{
+    type Item = IterationResult<VectorN<Scalar, Dimension>>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let is_extra = self.dx.iter().all(|xi| xi.abs() < self.eps) || self.iters >= self.max_iters;
+        let rate = self.rate();
+        let x0 = self.x.clone();
+        let mut derivative_calls = 0;
+        for batch in self.sampler.epoch() {
+            let g = self.objective.gradient(&self.x, &batch) * self.sign;
+            derivative_calls += batch.len();
+            self.r += g.component_mul(&g);
+            let delta = self.delta;
+            self.x -= g.zip_map(&self.r, |gi, ri| gi / (delta + ri.sqrt())) * rate;
+        }
+        self.dx = &self.x - x0;
+        self.iters += 1;
+
+        self.derivative_calls += derivative_calls;
+        Some(
+            IterationResult::new(self.x.clone(), self.dx.clone(), 0, is_extra)
+                .with_derivative_calls(derivative_calls),
+        )
+    }
+}
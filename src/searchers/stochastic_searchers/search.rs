@@ -0,0 +1,606 @@
+use crate::searchers::extremum_searcher;
+use crate::searchers::extremum_searcher::{FinalResult, IterationResult, SearchError};
+use nalgebra::{allocator::Allocator, DefaultAllocator, Dim, RealField, VectorN};
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use std::sync::Arc;
+
+// gradient of the i-th term
+pub type TermGradient<Scalar, Dimension> =
+    Arc<dyn Fn(VectorN<Scalar, Dimension>, usize) -> VectorN<Scalar, Dimension>>;
+
+pub trait StochasticSearcher<Scalar, Dimension>:
+    Iterator<Item = IterationResult<VectorN<Scalar, Dimension>>>
+    + extremum_searcher::Search<VectorN<Scalar, Dimension>>
+where
+    Scalar: RealField,
+    Dimension: Dim,
+    DefaultAllocator: Allocator<Scalar, Dimension>,
+{
+    // the learning rate used during the current epoch
+    fn rate(&self) -> Scalar;
+}
+
+// the mean of `terms` functions, only accessed through their gradients `df(x, i)`
+#[derive(Clone)]
+pub struct Objective<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: Dim,
+    DefaultAllocator: Allocator<Scalar, Dimension>,
+{
+    terms: usize,
+    df: TermGradient<Scalar, Dimension>,
+}
+
+impl<Scalar, Dimension> Objective<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: Dim,
+    DefaultAllocator: Allocator<Scalar, Dimension>,
+{
+    pub fn new(terms: usize, df: TermGradient<Scalar, Dimension>) -> Self {
+        Self { terms, df }
+    }
+    pub fn terms(&self) -> usize {
+        self.terms
+    }
+    // the mean gradient over the terms of `batch`
+    pub fn gradient(
+        &self,
+        x: &VectorN<Scalar, Dimension>,
+        batch: &[usize],
+    ) -> VectorN<Scalar, Dimension> {
+        batch.iter().fold(x.map(|_| Scalar::zero()), |a, &i| {
+            a + (self.df)(x.clone(), i)
+        }) / Scalar::from_usize(batch.len()).unwrap()
+    }
+}
+
+// splits the terms into minibatches, reshuffled at the start of every epoch
+pub struct Sampler {
+    order: Vec<usize>,
+    batch: usize,
+    rng: rand::rngs::StdRng,
+}
+
+impl Sampler {
+    pub fn new(terms: usize, batch: usize, seed: u64) -> Self {
+        Self {
+            order: (0..terms).collect(),
+            batch,
+            rng: rand::rngs::StdRng::seed_from_u64(seed),
+        }
+    }
+    pub fn epoch(&mut self) -> Vec<Vec<usize>> {
+        self.order.shuffle(&mut self.rng);
+        self.order
+            .chunks(self.batch)
+            .map(|batch| batch.to_vec())
+            .collect()
+    }
+}
+
+// learning rate on the k-th epoch
+#[derive(Clone)]
+pub enum Schedule<Scalar> {
+    Constant(Scalar),
+    // `initial * factor^(k / every)`
+    Step {
+        initial: Scalar,
+        factor: Scalar,
+        every: usize,
+    },
+    // `initial / (1 + decay * k)`
+    InverseTime {
+        initial: Scalar,
+        decay: Scalar,
+    },
+    // `initial * decay^k`
+    Exponential {
+        initial: Scalar,
+        decay: Scalar,
+    },
+}
+
+impl<Scalar> Schedule<Scalar>
+where
+    Scalar: RealField,
+{
+    pub fn rate(&self, k: usize) -> Scalar {
+        match self {
+            Schedule::Constant(rate) => *rate,
+            Schedule::Step {
+                initial,
+                factor,
+                every,
+            } => *initial * factor.powi((k / every) as i32),
+            Schedule::InverseTime { initial, decay } => {
+                *initial / (Scalar::one() + *decay * Scalar::from_usize(k).unwrap())
+            }
+            Schedule::Exponential { initial, decay } => *initial * decay.powi(k as i32),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub enum Method<Scalar> {
+    Sgd,
+    // `v' = beta * v - rate * g`
+    Momentum {
+        beta: Scalar,
+    },
+    // `delta` keeps the denominators of the adaptive methods away from zero
+    AdaGrad {
+        delta: Scalar,
+    },
+    RmsProp {
+        rho: Scalar,
+        delta: Scalar,
+    },
+    Adam {
+        beta1: Scalar,
+        beta2: Scalar,
+        delta: Scalar,
+    },
+}
+
+// every iteration is a whole epoch, derivative calls count the gradients of single terms
+pub struct Search<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: Dim,
+    DefaultAllocator: Allocator<Scalar, Dimension>,
+{
+    x: VectorN<Scalar, Dimension>,
+    dx: VectorN<Scalar, Dimension>,
+    func_calls: usize,
+    derivative_calls: usize,
+    iters: usize,
+    method: Box<
+        dyn StochasticSearcher<
+            Scalar,
+            Dimension,
+            Item = IterationResult<VectorN<Scalar, Dimension>>,
+        >,
+    >,
+}
+
+impl<Scalar, Dimension> Search<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: Dim,
+    DefaultAllocator: Allocator<Scalar, Dimension>,
+{
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        x0: VectorN<Scalar, Dimension>,
+        objective: Objective<Scalar, Dimension>,
+        comparator: std::cmp::Ordering,
+        method: Method<Scalar>,
+        schedule: Schedule<Scalar>,
+        batch: usize,
+        seed: u64,
+        eps: Scalar,
+        max_iters: usize,
+    ) -> Result<Self, SearchError> {
+        if objective.terms() == 0 {
+            return Err(SearchError::InvalidParameter("terms"));
+        }
+        if batch == 0 {
+            return Err(SearchError::InvalidParameter("batch"));
+        }
+        if let Schedule::Step { every: 0, .. } = schedule {
+            return Err(SearchError::InvalidParameter("every"));
+        }
+        let sampler = Sampler::new(objective.terms(), batch, seed);
+        let m: Box<
+            dyn StochasticSearcher<
+                Scalar,
+                Dimension,
+                Item = IterationResult<VectorN<Scalar, Dimension>>,
+            >,
+        > = match method {
+            Method::Sgd => Box::new(super::sgd::Sgd::new(
+                x0.clone(),
+                objective,
+                comparator,
+                Scalar::zero(),
+                schedule,
+                sampler,
+                eps,
+                max_iters,
+            )),
+            Method::Momentum { beta } => Box::new(super::sgd::Sgd::new(
+                x0.clone(),
+                objective,
+                comparator,
+                beta,
+                schedule,
+                sampler,
+                eps,
+                max_iters,
+            )),
+            Method::AdaGrad { delta } => Box::new(super::adagrad::AdaGrad::new(
+                x0.clone(),
+                objective,
+                comparator,
+                delta,
+                schedule,
+                sampler,
+                eps,
+                max_iters,
+            )),
+            Method::RmsProp { rho, delta } => Box::new(super::rmsprop::RmsProp::new(
+                x0.clone(),
+                objective,
+                comparator,
+                rho,
+                delta,
+                schedule,
+                sampler,
+                eps,
+                max_iters,
+            )),
+            Method::Adam {
+                beta1,
+                beta2,
+                delta,
+            } => Box::new(super::adam::Adam::new(
+                x0.clone(),
+                objective,
+                comparator,
+                beta1,
+                beta2,
+                delta,
+                schedule,
+                sampler,
+                eps,
+                max_iters,
+            )),
+        };
+        Ok(Self {
+            dx: x0.map(|_| Scalar::max_value()),
+            x: x0,
+            func_calls: m.func_calls(),
+            derivative_calls: m.derivative_calls(),
+            iters: 0,
+            method: m,
+        })
+    }
+    #[allow(clippy::too_many_arguments)]
+    pub fn result(
+        x0: VectorN<Scalar, Dimension>,
+        objective: Objective<Scalar, Dimension>,
+        comparator: std::cmp::Ordering,
+        method: Method<Scalar>,
+        schedule: Schedule<Scalar>,
+        batch: usize,
+        seed: u64,
+        eps: Scalar,
+        max_iters: usize,
+    ) -> Result<FinalResult<VectorN<Scalar, Dimension>>, SearchError> {
+        use crate::searchers::extremum_searcher::Search;
+        Self::new(
+            x0, objective, comparator, method, schedule, batch, seed, eps, max_iters,
+        )
+        .map(|mut search| search.result())
+    }
+    #[allow(clippy::too_many_arguments)]
+    pub fn Mnimimum(
+        x0: VectorN<Scalar, Dimension>,
+        objective: Objective<Scalar, Dimension>,
+        method: Method<Scalar>,
+        schedule: Schedule<Scalar>,
+        batch: usize,
+        seed: u64,
+        eps: Scalar,
+        max_iters: usize,
+    ) -> Result<FinalResult<VectorN<Scalar, Dimension>>, SearchError> {
+        use crate::searchers::extremum_searcher::Search;
+        Self::new(
+            x0,
+            objective,
+            std::cmp::Ordering::Less,
+            method,
+            schedule,
+            batch,
+            seed,
+            eps,
+            max_iters,
+        )
+        .map(|mut search| search.result())
+    }
+    #[allow(clippy::too_many_arguments)]
+    pub fn Maximum(
+        x0: VectorN<Scalar, Dimension>,
+        objective: Objective<Scalar, Dimension>,
+        method: Method<Scalar>,
+        schedule: Schedule<Scalar>,
+        batch: usize,
+        seed: u64,
+        eps: Scalar,
+        max_iters: usize,
+    ) -> Result<FinalResult<VectorN<Scalar, Dimension>>, SearchError> {
+        use crate::searchers::extremum_searcher::Search;
+        Self::new(
+            x0,
+            objective,
+            std::cmp::Ordering::Greater,
+            method,
+            schedule,
+            batch,
+            seed,
+            eps,
+            max_iters,
+        )
+        .map(|mut search| search.result())
+    }
+}
+
+impl<Scalar, Dimension> extremum_searcher::Search<VectorN<Scalar, Dimension>>
+    for Search<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: Dim,
+    DefaultAllocator: Allocator<Scalar, Dimension>,
+{
+    fn iters(&self) -> usize {
+        self.iters
+    }
+    fn func_calls(&self) -> usize {
+        self.func_calls
+    }
+    fn derivative_calls(&self) -> usize {
+        self.derivative_calls
+    }
+    fn x(&self) -> VectorN<Scalar, Dimension> {
+        self.x.clone()
+    }
+    fn dx(&self) -> VectorN<Scalar, Dimension> {
+        self.dx.clone()
+    }
+}
+
+impl<Scalar, Dimension> Iterator for Search<Scalar, Dimension>
+where
+    Scalar: RealField,
+    Dimension: Dim,
+    DefaultAllocator: Allocator<Scalar, Dimension>,
+{
+    type Item = IterationResult<VectorN<Scalar, Dimension>>;
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.method.next() {
+            Some(r) => {
+                self.iters += 1;
+                self.func_calls += r.func_calls();
+                self.derivative_calls += r.derivative_calls();
+                self.x = r.x();
+                self.dx = r.dx();
+                Some(
+                    IterationResult::new(
+                        self.x.clone(),
+                        self.dx.clone(),
+                        r.func_calls(),
+                        r.is_extra(),
+                    )
+                    .with_derivative_calls(r.derivative_calls()),
+                )
+            }
+            None => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nalgebra::{Vector2, U2};
+
+    // least squares over the points `(t, 2 + 3 t)`, the minimum is at (2, 3)
+    fn points(i: usize) -> (Vector2<f64>, f64) {
+        let t = i as f64 / 10.0 - 2.0;
+        (Vector2::new(1.0, t), 2.0 + 3.0 * t)
+    }
+
+    fn objective() -> Objective<f64, U2> {
+        Objective::new(
+            40,
+            Arc::new(|x: Vector2<f64>, i| {
+                let (a, b) = points(i);
+                a * (2.0 * (a.dot(&x) - b))
+            }),
+        )
+    }
+
+    fn loss(x: Vector2<f64>) -> f64 {
+        (0..40)
+            .map(|i| {
+                let (a, b) = points(i);
+                (a.dot(&x) - b).powi(2)
+            })
+            .sum::<f64>()
+            / 40.0
+    }
+
+    fn minimum(method: Method<f64>, schedule: Schedule<f64>) -> Vector2<f64> {
+        Search::Mnimimum(
+            Vector2::new(0.0, 0.0),
+            objective(),
+            method,
+            schedule,
+            4,
+            1,
+            1e-8,
+            2000,
+        )
+        .unwrap()
+        .x()
+    }
+
+    #[test]
+    fn every_method_fits_the_points() {
+        let methods = [
+            (
+                Method::Sgd,
+                Schedule::InverseTime {
+                    initial: 0.05,
+                    decay: 0.01,
+                },
+            ),
+            (
+                Method::Momentum { beta: 0.5 },
+                Schedule::Exponential {
+                    initial: 0.02,
+                    decay: 0.999,
+                },
+            ),
+            (Method::AdaGrad { delta: 1e-8 }, Schedule::Constant(0.5)),
+            (
+                Method::RmsProp {
+                    rho: 0.9,
+                    delta: 1e-8,
+                },
+                Schedule::Step {
+                    initial: 0.01,
+                    factor: 0.5,
+                    every: 100,
+                },
+            ),
+            (
+                Method::Adam {
+                    beta1: 0.9,
+                    beta2: 0.999,
+                    delta: 1e-8,
+                },
+                Schedule::Step {
+                    initial: 0.05,
+                    factor: 0.5,
+                    every: 100,
+                },
+            ),
+        ];
+        for (method, schedule) in methods.iter().cloned() {
+            assert!((minimum(method, schedule) - Vector2::new(2.0, 3.0)).norm() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn maximum_follows_the_minimum_of_the_negated_loss() {
+        let schedule = Schedule::InverseTime {
+            initial: 0.05,
+            decay: 0.01,
+        };
+        let negated = Objective::new(
+            40,
+            Arc::new(|x: Vector2<f64>, i| {
+                let (a, b) = points(i);
+                a * (-2.0 * (a.dot(&x) - b))
+            }),
+        );
+        let maximum = Search::Maximum(
+            Vector2::new(0.0, 0.0),
+            negated,
+            Method::Sgd,
+            schedule.clone(),
+            4,
+            1,
+            1e-8,
+            2000,
+        )
+        .unwrap();
+        let minimum = Search::result(
+            Vector2::new(0.0, 0.0),
+            objective(),
+            std::cmp::Ordering::Less,
+            Method::Sgd,
+            schedule,
+            4,
+            1,
+            1e-8,
+            2000,
+        )
+        .unwrap();
+        assert_eq!(maximum.x(), minimum.x());
+    }
+
+    #[test]
+    fn adam_decreases_the_loss() {
+        let mut search = Search::new(
+            Vector2::new(0.0, 0.0),
+            objective(),
+            std::cmp::Ordering::Less,
+            Method::Adam {
+                beta1: 0.9,
+                beta2: 0.999,
+                delta: 1e-8,
+            },
+            Schedule::Constant(0.05),
+            4,
+            1,
+            1e-8,
+            100,
+        )
+        .unwrap();
+        let losses: Vec<f64> = search.by_ref().take(20).map(|r| loss(r.x())).collect();
+        assert!(losses[19] < losses[0] / 10.0);
+    }
+
+    #[test]
+    fn same_seed_gives_the_same_path() {
+        let path = |seed| {
+            Search::new(
+                Vector2::new(0.0, 0.0),
+                objective(),
+                std::cmp::Ordering::Less,
+                Method::Sgd,
+                Schedule::Constant(0.01),
+                3,
+                seed,
+                1e-8,
+                100,
+            )
+            .unwrap()
+            .take(5)
+            .map(|r| r.x())
+            .collect::<Vec<_>>()
+        };
+        assert_eq!(path(5), path(5));
+    }
+
+    #[test]
+    fn invalid_parameters_are_rejected() {
+        let new = |objective, schedule, batch| {
+            Search::new(
+                Vector2::new(0.0, 0.0),
+                objective,
+                std::cmp::Ordering::Less,
+                Method::Sgd,
+                schedule,
+                batch,
+                1,
+                1e-8,
+                100,
+            )
+            .err()
+        };
+        let empty = Objective::new(0, Arc::new(|x: Vector2<f64>, _| x));
+        assert_eq!(
+            new(empty, Schedule::Constant(0.1), 4),
+            Some(SearchError::InvalidParameter("terms"))
+        );
+        assert_eq!(
+            new(objective(), Schedule::Constant(0.1), 0),
+            Some(SearchError::InvalidParameter("batch"))
+        );
+        let schedule = Schedule::Step {
+            initial: 0.1,
+            factor: 0.5,
+            every: 0,
+        };
+        assert_eq!(
+            new(objective(), schedule, 4),
+            Some(SearchError::InvalidParameter("every"))
+        );
+    }
+}